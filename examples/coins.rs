@@ -0,0 +1,314 @@
+#![cfg_attr(test, feature(test))]
+
+use std::fmt::{Debug, Display, Formatter};
+
+use rival::{CloneCacheKey, Evaluate, LazyZobristHash, Moves, PlayClone, Value};
+
+/// Number of players taking turns.
+pub const PLAYERS: usize = 3;
+
+/// Maximum number of coins in a row.
+pub const COINS: usize = 12;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// A row of coins, from which each player in turn takes either the leftmost or
+/// the rightmost coin. When all coins are gone, the player who collected the
+/// highest total value wins.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Coins {
+    turn: usize,
+    row: [Value; COINS],
+    left: usize,
+    right: usize,
+    scores: [Value; PLAYERS],
+    bounded: bool,
+}
+
+impl Coins {
+    pub fn new(coins: &[Value]) -> Self {
+        assert!(coins.len() <= COINS, "at most {} coins", COINS);
+
+        let mut row = [0; COINS];
+        row[..coins.len()].copy_from_slice(coins);
+
+        Coins {
+            turn: 0,
+            row,
+            left: 0,
+            right: coins.len(),
+            scores: [0; PLAYERS],
+            bounded: true,
+        }
+    }
+
+    /// Hides the bounds on the scores from the search algorithm, disabling
+    /// pruning.
+    pub fn unbounded(mut self) -> Self {
+        self.bounded = false;
+        self
+    }
+
+    pub fn scores(&self) -> [Value; PLAYERS] {
+        self.scores
+    }
+
+    pub fn remaining(&self) -> &[Value] {
+        &self.row[self.left..self.right]
+    }
+}
+
+impl Evaluate<PLAYERS> for Coins {
+    type Score = Value;
+
+    fn turn(&self) -> usize {
+        self.turn
+    }
+
+    fn evaluate(&self) -> [Value; PLAYERS] {
+        self.scores
+    }
+
+    fn max_score(&self) -> Option<Value> {
+        self.max_sum()
+    }
+
+    fn max_sum(&self) -> Option<Value> {
+        self.bounded
+            .then(|| self.scores.iter().sum::<Value>() + self.remaining().iter().sum::<Value>())
+    }
+}
+
+impl Moves for Coins {
+    type Move = Side;
+    type Iter<'a> = <Vec<Self::Move> as IntoIterator>::IntoIter;
+
+    fn moves(&self) -> Self::Iter<'static> {
+        match self.right - self.left {
+            0 => Vec::new(),
+            1 => vec![Side::Left],
+            _ => vec![Side::Left, Side::Right],
+        }
+        .into_iter()
+    }
+}
+
+impl PlayClone for Coins {
+    fn play(&mut self, m: &Self::Move) {
+        let coin = match m {
+            Side::Left => {
+                self.left += 1;
+                self.row[self.left - 1]
+            }
+            Side::Right => {
+                self.right -= 1;
+                self.row[self.right]
+            }
+        };
+
+        self.scores[self.turn] += coin;
+        self.turn = (self.turn + 1) % PLAYERS;
+    }
+}
+
+impl LazyZobristHash for Coins {}
+
+impl CloneCacheKey for Coins {}
+
+impl Display for Coins {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} (player {} to move, scores {:?})",
+            self.remaining(),
+            self.turn,
+            self.scores
+        )
+    }
+}
+
+impl Debug for Coins {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use rival::{BestReply, MaxN, Mcts, Moves, Paranoid, Rival};
+    use test::Bencher;
+
+    use crate::{Coins, Side, PLAYERS};
+
+    /// Capacity of the transposition table of computer players in these tests.
+    const CAP: usize = 2000;
+
+    #[test]
+    fn test_coins_maxn_full_game() {
+        let mut game = Coins::new(&[3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8]);
+        let mut rival: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+
+        for _ in 0..12 {
+            assert_eq!(rival.play(&mut game, 12), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.scores().iter().sum::<i16>(), 52);
+    }
+
+    #[test]
+    fn test_coins_maxn_best_move() {
+        let mut game = Coins::new(&[1, 1, 1, 1, 1, 20]);
+        let mut rival: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 6), Ok(Side::Right), "{game}");
+    }
+
+    #[test]
+    fn test_coins_maxn_lookahead() {
+        // Greedily taking the 6 on the right would leave the 8 for the next player
+        let mut game = Coins::new(&[1, 1, 6, 8, 6]);
+        let mut rival: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 5), Ok(Side::Left), "{game}");
+    }
+
+    #[test]
+    fn test_coins_maxn_pruning() {
+        let coins = [30, 1, 4, 10, 5, 9, 20, 6, 5, 3, 5, 8];
+
+        let mut plain: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+        let plain_best = plain.get_best(&mut Coins::new(&coins).unbounded(), 12);
+
+        let mut pruned: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+        let pruned_best = pruned.get_best(&mut Coins::new(&coins), 12);
+
+        assert_eq!(plain_best, pruned_best);
+        assert!(
+            pruned.nodes() < plain.nodes(),
+            "{} >= {}",
+            pruned.nodes(),
+            plain.nodes()
+        );
+    }
+
+    #[test]
+    fn test_coins_paranoid_full_game() {
+        let mut game = Coins::new(&[3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8]);
+        let mut rival: Rival<_, Paranoid, PLAYERS, CAP> = Rival::new();
+
+        for _ in 0..12 {
+            assert_eq!(rival.play(&mut game, 12), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.scores().iter().sum::<i16>(), 52);
+    }
+
+    #[test]
+    fn test_coins_paranoid_best_move() {
+        // Taking the 1 on the left is only better if the other players do not team up
+        let mut game = Coins::new(&[1, 3, 2, 6, 3]);
+        let mut paranoid: Rival<_, Paranoid, PLAYERS, CAP> = Rival::new();
+        let mut max_n: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+
+        assert_eq!(paranoid.get_best(&mut game, 5), Ok(Side::Right), "{game}");
+        assert_eq!(max_n.get_best(&mut game, 5), Ok(Side::Left), "{game}");
+    }
+
+    #[test]
+    fn test_coins_best_reply_full_game() {
+        let mut game = Coins::new(&[3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8]);
+        let mut rival: Rival<_, BestReply, PLAYERS, CAP> = Rival::new();
+
+        for _ in 0..12 {
+            assert_eq!(rival.play(&mut game, 12), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.scores().iter().sum::<i16>(), 52);
+    }
+
+    #[test]
+    fn test_coins_best_reply_best_move() {
+        let mut game = Coins::new(&[1, 1, 1, 1, 1, 20]);
+        let mut rival: Rival<_, BestReply, PLAYERS, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 6), Ok(Side::Right), "{game}");
+    }
+
+    #[test]
+    fn test_coins_mcts_full_game() {
+        let mut game = Coins::new(&[3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8]);
+        let mut rival: Rival<_, Mcts, PLAYERS, CAP> = Rival::new();
+
+        for _ in 0..12 {
+            assert_eq!(rival.play(&mut game, 1), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.scores().iter().sum::<i16>(), 52);
+    }
+
+    #[test]
+    fn test_coins_mcts_best_move() {
+        let mut game = Coins::new(&[1, 1, 1, 1, 1, 20]);
+        let mut rival: Rival<_, Mcts, PLAYERS, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 1), Ok(Side::Right), "{game}");
+    }
+
+    #[bench]
+    fn bench_coins_maxn(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+            let mut game = Coins::new(&[30, 1, 4, 10, 5, 9, 20, 6, 5, 3, 5, 8]).unbounded();
+
+            for _ in 0..12 {
+                rival.play(&mut game, 12).unwrap();
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_coins_maxn_pruned(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, MaxN, PLAYERS, CAP> = Rival::new();
+            let mut game = Coins::new(&[30, 1, 4, 10, 5, 9, 20, 6, 5, 3, 5, 8]);
+
+            for _ in 0..12 {
+                rival.play(&mut game, 12).unwrap();
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_coins_paranoid(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Paranoid, PLAYERS, CAP> = Rival::new();
+            let mut game = Coins::new(&[30, 1, 4, 10, 5, 9, 20, 6, 5, 3, 5, 8]);
+
+            for _ in 0..12 {
+                rival.play(&mut game, 12).unwrap();
+            }
+        });
+    }
+
+    #[bench]
+    fn bench_coins_best_reply(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, BestReply, PLAYERS, CAP> = Rival::new();
+            let mut game = Coins::new(&[30, 1, 4, 10, 5, 9, 20, 6, 5, 3, 5, 8]);
+
+            for _ in 0..12 {
+                rival.play(&mut game, 12).unwrap();
+            }
+        });
+    }
+}
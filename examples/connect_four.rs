@@ -0,0 +1,993 @@
+#![cfg_attr(test, feature(test))]
+
+use std::{
+    fmt::{Debug, Display, Formatter},
+    ops::Not,
+};
+
+use rival::{CloneCacheKey, EvaluateZeroSum, LazyZobristHash, Moves, NullMove, PlayClone, Value};
+
+pub const COLUMNS: usize = 7;
+
+pub const ROWS: usize = 6;
+
+/// Value of connecting four discs, which is more than any unfinished game is
+/// worth.
+pub const WIN: Value = 10000;
+
+/// Columns in the order they are searched, as the center columns tend to be
+/// the strongest.
+const ORDER: [usize; COLUMNS] = [3, 2, 4, 1, 5, 0, 6];
+
+/// Directions in which lines of discs can be formed.
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Disc {
+    Red,
+    Yellow,
+}
+
+impl Not for Disc {
+    type Output = Disc;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Disc::Red => Disc::Yellow,
+            Disc::Yellow => Disc::Red,
+        }
+    }
+}
+
+/// Players take turns dropping a disc into one of the columns, where it falls
+/// to the lowest free row. The first player to connect four discs
+/// horizontally, vertically or diagonally wins.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ConnectFour {
+    turn: Disc,
+    grid: [[Option<Disc>; ROWS]; COLUMNS],
+    heights: [usize; COLUMNS],
+    reversed: bool,
+    prioritized: bool,
+    passing: bool,
+}
+
+impl ConnectFour {
+    pub fn new() -> Self {
+        ConnectFour {
+            turn: Disc::Red,
+            grid: [[None; ROWS]; COLUMNS],
+            heights: [0; COLUMNS],
+            reversed: false,
+            prioritized: true,
+            passing: false,
+        }
+    }
+
+    /// Generates the columns from the outside in, the worst order for the
+    /// search to try them in.
+    pub fn reversed(mut self) -> Self {
+        self.reversed = true;
+        self
+    }
+
+    /// Hides which moves win or block a win from the search, so that moves
+    /// are searched in the order they are generated.
+    pub fn unprioritized(mut self) -> Self {
+        self.prioritized = false;
+        self
+    }
+
+    /// Lets the search pretend the players can pass the turn, even though
+    /// having to drop a disc can be a disadvantage.
+    pub fn passing(mut self) -> Self {
+        self.passing = true;
+        self
+    }
+
+    /// Returns the discs of every line of four cells on the board.
+    fn lines(&self) -> impl Iterator<Item = [Option<Disc>; 4]> + '_ {
+        DIRECTIONS.iter().flat_map(move |&(dx, dy)| {
+            (0..COLUMNS as isize).flat_map(move |x| {
+                (0..ROWS as isize).filter_map(move |y| {
+                    let (end_x, end_y) = (x + 3 * dx, y + 3 * dy);
+                    if end_x >= COLUMNS as isize || end_y < 0 || end_y >= ROWS as isize {
+                        return None;
+                    }
+
+                    Some(
+                        [0, 1, 2, 3]
+                            .map(|i| self.grid[(x + i * dx) as usize][(y + i * dy) as usize]),
+                    )
+                })
+            })
+        })
+    }
+
+    /// Indicates whether dropping a disc of the given color into a column
+    /// would connect four discs.
+    fn connects(&self, column: usize, disc: Disc) -> bool {
+        let (x, y) = (column as isize, self.heights[column] as isize);
+        let count = |dx: isize, dy: isize| {
+            (1..4)
+                .take_while(|i| {
+                    let (x, y) = (x + i * dx, y + i * dy);
+                    (0..COLUMNS as isize).contains(&x)
+                        && (0..ROWS as isize).contains(&y)
+                        && self.grid[x as usize][y as usize] == Some(disc)
+                })
+                .count()
+        };
+
+        DIRECTIONS
+            .iter()
+            .any(|&(dx, dy)| count(dx, dy) + count(-dx, -dy) >= 3)
+    }
+
+    pub fn winner(&self) -> Option<Disc> {
+        self.lines().find_map(|line| {
+            line.iter()
+                .all(|&disc| disc == line[0])
+                .then_some(line[0])
+                .flatten()
+        })
+    }
+}
+
+impl Default for ConnectFour {
+    fn default() -> Self {
+        ConnectFour::new()
+    }
+}
+
+impl EvaluateZeroSum for ConnectFour {
+    type Score = Value;
+
+    fn min_turn(&self) -> bool {
+        self.turn == Disc::Yellow
+    }
+
+    fn evaluate(&self) -> Value {
+        match self.winner() {
+            Some(Disc::Red) => WIN,
+            Some(Disc::Yellow) => -WIN,
+            // Lines that can still be completed by one of the players
+            None => self
+                .lines()
+                .map(|line| {
+                    let red = line.iter().filter(|&&disc| disc == Some(Disc::Red)).count();
+                    let yellow = line
+                        .iter()
+                        .filter(|&&disc| disc == Some(Disc::Yellow))
+                        .count();
+
+                    match (red, yellow) {
+                        (0, 0) => 0,
+                        (red, 0) => 1 << (2 * red),
+                        (0, yellow) => -(1 << (2 * yellow)),
+                        _ => 0,
+                    }
+                })
+                .sum(),
+        }
+    }
+
+    fn max_value(&self) -> Option<Value> {
+        Some(WIN)
+    }
+
+    fn is_win(&self) -> Option<bool> {
+        self.winner().map(|disc| disc == Disc::Red)
+    }
+}
+
+impl Moves for ConnectFour {
+    type Move = usize;
+    type Iter<'a> = <Vec<Self::Move> as IntoIterator>::IntoIter;
+
+    fn moves(&self) -> Self::Iter<'static> {
+        if self.winner().is_some() {
+            return Vec::new().into_iter();
+        }
+
+        let mut moves: Vec<_> = ORDER
+            .iter()
+            .copied()
+            .filter(|&column| self.heights[column] < ROWS)
+            .collect();
+
+        if self.reversed {
+            moves.reverse();
+        }
+
+        moves.into_iter()
+    }
+
+    fn priority(&self, m: &Self::Move) -> i32 {
+        if !self.prioritized {
+            0
+        } else if self.connects(*m, self.turn) {
+            2
+        } else if self.connects(*m, !self.turn) {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+impl PlayClone for ConnectFour {
+    fn play(&mut self, m: &Self::Move) {
+        self.grid[*m][self.heights[*m]] = Some(self.turn);
+        self.heights[*m] += 1;
+        self.turn = !self.turn;
+    }
+}
+
+impl NullMove for ConnectFour {
+    fn null_move_safe(&self) -> bool {
+        self.passing
+    }
+
+    fn play_null(&mut self) -> Self {
+        let clone = *self;
+        self.turn = !self.turn;
+        clone
+    }
+}
+
+impl LazyZobristHash for ConnectFour {}
+
+impl CloneCacheKey for ConnectFour {}
+
+impl Display for ConnectFour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in (0..ROWS).rev() {
+            for x in 0..COLUMNS {
+                match self.grid[x][y] {
+                    Some(Disc::Red) => write!(f, "R")?,
+                    Some(Disc::Yellow) => write!(f, "Y")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for ConnectFour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use std::{
+        sync::{Arc, Mutex},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
+    };
+
+    use rival::{
+        BestReply, MaxN, Moves, MtdF, Negamax, NegamaxParams, Paranoid, PlayClone, Rival,
+        RivalError, Score, SearchLimits, SearchReport, StopHandle, Strategy, TimeManager, Value,
+        WithNullMoves,
+    };
+    use test::Bencher;
+
+    use crate::{ConnectFour, COLUMNS, ROWS, WIN};
+
+    /// Capacity of the transposition table of computer players in these tests.
+    const CAP: usize = 200000;
+
+    /// Capacity of a transposition table too small to make a difference, to
+    /// compare the number of states different searches visit on their own.
+    const TINY_CAP: usize = 1;
+
+    #[test]
+    fn test_connect_four_negamax_win() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 1, 0, 1, 0, 2] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 2), Ok(0), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_negamax_block() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 4), Ok(3), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_negamax_vs_maxn() {
+        // Reusing the transposition table between searches must not affect the outcome. Equally
+        // good moves may be told apart differently, so only the values of the root are compared.
+        let mut negamax: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut pvs: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+        let mut paranoid: Rival<_, Paranoid, 2, CAP> = Rival::new();
+
+        for depth in 1..=6 {
+            let limits = SearchLimits::new().with_depth(depth);
+            let mut max_n: Rival<_, MaxN, 2, CAP> = Rival::new();
+            let expected = max_n
+                .analyze(&mut ConnectFour::new(), limits)
+                .map(|report| report.score);
+
+            assert_eq!(
+                negamax
+                    .analyze(&mut ConnectFour::new(), limits)
+                    .map(|report| report.score),
+                expected,
+                "depth {}",
+                depth
+            );
+            assert_eq!(
+                pvs.analyze(&mut ConnectFour::new(), limits)
+                    .map(|report| report.score),
+                expected,
+                "depth {}",
+                depth
+            );
+            assert_eq!(
+                paranoid
+                    .analyze(&mut ConnectFour::new(), limits)
+                    .map(|report| report.score),
+                expected,
+                "depth {}",
+                depth
+            );
+        }
+    }
+
+    #[test]
+    fn test_connect_four_principal_variation_block() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+
+        assert_eq!(rival.get_best(&mut game, 4), Ok(3), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_principal_variation_nodes() {
+        let mut plain: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let plain_best = plain.get_best(&mut ConnectFour::new(), 7);
+
+        let mut pvs: Rival<_, Negamax, 2, TINY_CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+        let pvs_best = pvs.get_best(&mut ConnectFour::new(), 7);
+
+        assert_eq!(plain_best, pvs_best);
+        assert!(
+            pvs.nodes() < plain.nodes(),
+            "{} >= {}",
+            pvs.nodes(),
+            plain.nodes()
+        );
+    }
+
+    #[test]
+    fn test_connect_four_negamax_hash_move() {
+        // Searching the best moves of shallower searches first makes up for a poor move order,
+        // so that all shallower searches together cost less than going straight to the depth
+        let mut deepening: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut deepening_best = None;
+        for depth in 1..=6 {
+            deepening_best = Some(deepening.get_best(&mut ConnectFour::new().reversed(), depth));
+        }
+
+        let mut direct: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let direct_best = direct.get_best(&mut ConnectFour::new().reversed(), 6);
+
+        assert_eq!(deepening_best, Some(direct_best));
+        assert!(
+            deepening.nodes() < direct.nodes(),
+            "{} >= {}",
+            deepening.nodes(),
+            direct.nodes()
+        );
+    }
+
+    #[test]
+    fn test_connect_four_negamax_priority() {
+        // Blocking the three discs in the center is found quickly, even when searched last
+        let mut game = ConnectFour::new().reversed();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut prioritized: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let prioritized_best = prioritized.get_best(&mut game, 5);
+
+        let mut unprioritized: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let unprioritized_best = unprioritized.get_best(&mut game.unprioritized(), 5);
+
+        assert_eq!(prioritized_best, Ok(3), "{game}");
+        assert_eq!(unprioritized_best, Ok(3), "{game}");
+        assert!(
+            prioritized.nodes() < unprioritized.nodes(),
+            "{} >= {}",
+            prioritized.nodes(),
+            unprioritized.nodes()
+        );
+    }
+
+    #[test]
+    fn test_connect_four_negamax_null_move() {
+        // Red has to block yellow's column, which passing cannot do
+        let mut game = ConnectFour::new();
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut passing: Rival<_, Negamax<WithNullMoves>, 2, TINY_CAP> =
+            Rival::with_strategy(Negamax::new().with_null_moves());
+        let passing_best = passing.get_best(&mut game.passing(), 7);
+
+        let mut moving: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let moving_best = moving.get_best(&mut game, 7);
+
+        // Null-move pruning is opt-in, and can be disabled again
+        let mut default: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        default.get_best(&mut game.passing(), 7).unwrap();
+
+        let mut disabled: Rival<_, Negamax<WithNullMoves>, 2, TINY_CAP> =
+            Rival::with_strategy(Negamax::new().with_null_moves().with_null_move_reduction(0));
+        disabled.get_best(&mut game.passing(), 7).unwrap();
+
+        assert_eq!(passing_best, Ok(3), "{game}");
+        assert_eq!(moving_best, Ok(3), "{game}");
+        assert!(
+            passing.nodes() < moving.nodes(),
+            "{} >= {}",
+            passing.nodes(),
+            moving.nodes()
+        );
+        assert_eq!(default.nodes(), moving.nodes());
+        assert_eq!(disabled.nodes(), moving.nodes());
+    }
+
+    #[test]
+    fn test_connect_four_negamax_late_move_reductions() {
+        // Red has to block yellow's column, which is not one of the late moves
+        let mut game = ConnectFour::new();
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let params = NegamaxParams {
+            late_move_reduction: 1,
+            ..NegamaxParams::default()
+        };
+        let mut reduced: Rival<_, Negamax, 2, TINY_CAP> =
+            Rival::with_strategy(Negamax::new().with_params(params));
+        let reduced_best = reduced.get_best(&mut game, 7);
+
+        let mut full: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let full_best = full.get_best(&mut game, 7);
+
+        assert_eq!(reduced_best, Ok(3), "{game}");
+        assert_eq!(full_best, Ok(3), "{game}");
+        assert!(
+            reduced.nodes() < full.nodes(),
+            "{} >= {}",
+            reduced.nodes(),
+            full.nodes()
+        );
+    }
+
+    #[test]
+    fn test_connect_four_negamax_heuristics() {
+        // Killer moves and history carry over from one depth to the next
+        let mut with: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut without: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_heuristics(false));
+
+        for depth in 1..=6 {
+            assert_eq!(
+                with.get_best(&mut ConnectFour::new().reversed(), depth),
+                without.get_best(&mut ConnectFour::new().reversed(), depth),
+                "depth {}",
+                depth
+            );
+        }
+
+        assert!(
+            with.nodes() < without.nodes(),
+            "{} >= {}",
+            with.nodes(),
+            without.nodes()
+        );
+    }
+
+    #[test]
+    fn test_connect_four_mtdf_block() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 4), Ok(3), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_mtdf_within() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+        let best = rival.get_best_within(&mut game, Duration::from_millis(100));
+
+        assert_eq!(best, Ok(3), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_search_nodes() {
+        // The same limit on the number of nodes always leads to the same move
+        let limits = SearchLimits::new().with_nodes(5000);
+        let mut first: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut second: Rival<_, Negamax, 2, CAP> = Rival::new();
+
+        assert_eq!(
+            first.search(&mut ConnectFour::new(), limits),
+            second.search(&mut ConnectFour::new(), limits)
+        );
+        assert_eq!(first.nodes(), second.nodes());
+        assert!(first.nodes() <= 5001, "{}", first.nodes());
+    }
+
+    #[test]
+    fn test_connect_four_search_soft_time() {
+        // Once the soft time has passed, no deeper search is started
+        let limits = SearchLimits::new().with_soft_time(Duration::ZERO);
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut shallow: Rival<_, Negamax, 2, CAP> = Rival::new();
+
+        assert_eq!(
+            rival.search(&mut ConnectFour::new(), limits),
+            shallow.get_best(&mut ConnectFour::new(), 1)
+        );
+        assert_eq!(rival.nodes(), shallow.nodes());
+    }
+
+    #[test]
+    fn test_connect_four_search_forced_win() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 1, 0, 1, 0, 2] {
+            game.play(&m);
+        }
+
+        let limits = SearchLimits::new().with_depth(6);
+        let mut stopping: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut continuing: Rival<_, Negamax, 2, CAP> = Rival::new();
+
+        assert_eq!(
+            stopping.search(&mut game, limits.with_stop_on_forced_win(true)),
+            Ok(0),
+            "{game}"
+        );
+        assert_eq!(continuing.search(&mut game, limits), Ok(0), "{game}");
+        assert!(
+            stopping.nodes() < continuing.nodes(),
+            "{} >= {}",
+            stopping.nodes(),
+            continuing.nodes()
+        );
+    }
+
+    /// Asserts that a search with a timeout does not take much longer than that.
+    fn assert_within<S: Strategy<ConnectFour, 2, CAP>>(strategy: S) {
+        let timeout = Duration::from_millis(100);
+        let tolerance = Duration::from_millis(100);
+        let mut rival: Rival<_, S, 2, CAP> = Rival::with_strategy(strategy);
+
+        let start = Instant::now();
+        let best = rival.get_best_within(&mut ConnectFour::new(), timeout);
+        let elapsed = start.elapsed();
+
+        assert!(best.is_ok(), "{:?}", best);
+        assert!(elapsed < timeout + tolerance, "{:?}", elapsed);
+    }
+
+    #[test]
+    fn test_connect_four_deadline() {
+        assert_within(Negamax::new());
+        assert_within(Negamax::new().with_principal_variation(true));
+        assert_within(MtdF::new());
+        assert_within(MaxN::default());
+        assert_within(Paranoid::default());
+    }
+
+    #[test]
+    fn test_connect_four_analyze() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let report = rival.analyze(&mut game, SearchLimits::new().with_depth(5));
+
+        let report = report.unwrap();
+        assert_eq!(report.best, 3, "{game}");
+        assert_eq!(report.depth, 5);
+        assert_eq!(report.nodes, rival.nodes());
+        assert!(report.cache_hits <= report.cache_probes);
+        assert!(report.hit_rate() > 0.0);
+
+        // The principal variation consists of legal moves, starting with the best move
+        assert_eq!(report.principal_variation[0], 3);
+        assert!(report.principal_variation.len() <= 5);
+        for m in report.principal_variation {
+            assert!(game.moves().any(|legal| legal == m), "{}\n{}", m, game);
+            game.play(&m);
+        }
+    }
+
+    fn assert_win_in<S: Strategy<ConnectFour, 2, CAP, Score = Value>>(strategy: S) {
+        // Red can win right away at either end of its row, or later by playing elsewhere first
+        let mut game = ConnectFour::new();
+        for m in [2, 2, 3, 3, 4, 6] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, S, 2, CAP> = Rival::with_strategy(strategy);
+        let report = rival
+            .analyze(&mut game, SearchLimits::new().with_depth(5))
+            .unwrap();
+
+        assert!([1, 5].contains(&report.best), "{}\n{}", report.best, game);
+        assert_eq!(report.score, Some(Value::FORCED_WIN - 1), "{game}");
+        assert_eq!(report.win_in, Some(1), "{game}");
+
+        // Yellow cannot block both ends, and loses after its next move
+        let mut game = ConnectFour::new();
+        for m in [2, 2, 3, 3, 4] {
+            game.play(&m);
+        }
+        let report = rival
+            .analyze(&mut game, SearchLimits::new().with_depth(5))
+            .unwrap();
+
+        assert_eq!(report.score, Some(2 - Value::FORCED_WIN), "{game}");
+        assert_eq!(report.win_in, Some(-2), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_win_in() {
+        assert_win_in(Negamax::new());
+        assert_win_in(Negamax::new().with_principal_variation(true));
+        assert_win_in(MtdF::new());
+        assert_win_in(Paranoid::default());
+    }
+
+    #[test]
+    fn test_connect_four_observer() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let observed = reports.clone();
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::new().with_observer(move |report| observed.lock().unwrap().push(report.clone()));
+
+        let report = rival.analyze(&mut ConnectFour::new(), SearchLimits::new().with_depth(8));
+        let report = report.unwrap();
+        let reports = reports.lock().unwrap();
+
+        // Every completed search is reported, possibly followed by progress during the next one
+        let mut depths: Vec<_> = reports.iter().map(|report| report.depth).collect();
+        depths.dedup();
+        assert_eq!(depths, (1..=8).collect::<Vec<_>>());
+        assert!(reports
+            .windows(2)
+            .all(|pair| pair[0].nodes <= pair[1].nodes));
+        // Progress reports tell which depth is being searched, on top of the last completed search
+        assert!(reports.iter().all(|report| report
+            .searching_depth
+            .is_none_or(|depth| depth == report.depth + 1)));
+        assert_eq!(report.searching_depth, None);
+
+        let last = reports.last().unwrap();
+        assert_eq!(
+            *last,
+            SearchReport {
+                elapsed: last.elapsed,
+                ..report
+            }
+        );
+    }
+
+    #[test]
+    fn test_connect_four_observer_within() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let observed = reports.clone();
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::new().with_observer(move |report| observed.lock().unwrap().push(report.clone()));
+
+        let best = rival.get_best_within(&mut ConnectFour::new(), Duration::from_millis(200));
+        let reports = reports.lock().unwrap();
+
+        // Progress reports aside, every completed search is reported exactly once
+        let depths: Vec<_> = reports
+            .iter()
+            .filter(|report| report.searching_depth.is_none())
+            .map(|report| report.depth)
+            .collect();
+        assert!(best.is_ok());
+        assert!(!depths.is_empty());
+        assert_eq!(depths, (1..=depths.len() as u8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_connect_four_time_manager() {
+        let limits = TimeManager::new(Duration::from_secs(60))
+            .with_increment(Duration::from_secs(1))
+            .limits();
+        let expected = SearchLimits::new()
+            .with_soft_time(Duration::from_millis(1500))
+            .with_extended_soft_time(Duration::from_secs(3))
+            .with_hard_time(Duration::from_secs(6));
+        assert_eq!(limits, expected);
+
+        // The last move before the clock is reset may use all but the reserve
+        let limits = TimeManager::new(Duration::from_secs(60))
+            .with_moves_to_go(1)
+            .limits();
+        let expected = SearchLimits::new()
+            .with_soft_time(Duration::from_secs(27))
+            .with_extended_soft_time(Duration::from_secs(54))
+            .with_hard_time(Duration::from_secs(54));
+        assert_eq!(limits, expected);
+    }
+
+    #[test]
+    fn test_connect_four_clock() {
+        // Even a player whose every search runs until it is interrupted keeps time on the clock
+        let increment = Duration::from_millis(20);
+        let mut clock = Duration::from_secs(2);
+
+        for _ in 0..COLUMNS * ROWS {
+            let limits = TimeManager::new(clock).with_increment(increment).limits();
+            let hard_time = limits.hard_time().unwrap();
+
+            assert!(hard_time < clock, "{:?} >= {:?}", hard_time, clock);
+            clock = clock - hard_time + increment;
+        }
+    }
+
+    /// Asserts the winning move is ranked first, at the given score.
+    fn assert_best_moves<S: Strategy<ConnectFour, 2, CAP, Score = Value>>(strategy: S, win: Value) {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 1, 0, 1, 0, 2] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, S, 2, CAP> = Rival::with_strategy(strategy);
+        let ranked = rival.get_best_moves(&mut game, 2, 3).unwrap();
+
+        assert_eq!(ranked.len(), 3, "{game}");
+        assert_eq!(ranked[0], (0, win, vec![0]), "{game}");
+        assert!(ranked[1].1 < win, "{:?}", ranked);
+        assert!(ranked[2].1 <= ranked[1].1, "{:?}", ranked);
+        assert!(
+            ranked[1].0 != 0 && ranked[2].0 != 0 && ranked[1].0 != ranked[2].0,
+            "{:?}",
+            ranked
+        );
+    }
+
+    #[test]
+    fn test_connect_four_best_moves() {
+        // Strategies built on alpha-beta search count the moves to the win
+        assert_best_moves(Negamax::new(), Value::FORCED_WIN - 1);
+        assert_best_moves(
+            Negamax::new().with_principal_variation(true),
+            Value::FORCED_WIN - 1,
+        );
+        assert_best_moves(MtdF::new(), Value::FORCED_WIN - 1);
+        assert_best_moves(MaxN::default(), WIN);
+        assert_best_moves(Paranoid::default(), Value::FORCED_WIN - 1);
+        assert_best_moves(BestReply::default(), WIN);
+    }
+
+    #[test]
+    fn test_connect_four_evaluate_moves() {
+        let mut game = ConnectFour::new();
+
+        for m in [0, 1, 0, 1, 0, 2] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let evaluated = rival
+            .evaluate_moves(&mut game, SearchLimits::new().with_depth(4))
+            .unwrap();
+
+        // Every move is scored, in the order they are generated
+        let moves: Vec<_> = game.moves().collect();
+        assert_eq!(
+            evaluated.iter().map(|(m, _)| *m).collect::<Vec<_>>(),
+            moves,
+            "{game}"
+        );
+        assert!(
+            evaluated
+                .iter()
+                .all(|&(m, score)| (score == Value::FORCED_WIN - 1) == (m == 0)),
+            "{:?}",
+            evaluated
+        );
+
+        // The scores agree with ranking all moves at the same depth
+        let ranked = rival.get_best_moves(&mut game, 4, moves.len()).unwrap();
+        for (m, score) in &evaluated {
+            assert!(
+                ranked
+                    .iter()
+                    .any(|(ranked, ranked_score, _)| ranked == m && ranked_score == score),
+                "{:?}\n{:?}",
+                evaluated,
+                ranked
+            );
+        }
+    }
+
+    #[test]
+    fn test_connect_four_evaluate_moves_nodes() {
+        // The first depth is always scored in full, even beyond the node budget
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let evaluated = rival
+            .evaluate_moves(
+                &mut ConnectFour::new(),
+                SearchLimits::new().with_depth(20).with_nodes(1),
+            )
+            .unwrap();
+
+        assert_eq!(evaluated.len(), 7);
+    }
+
+    #[test]
+    fn test_connect_four_evaluate_moves_unlimited() {
+        // Without any limit, the moves are scored at a depth that takes a reasonable time
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let evaluated = rival
+            .evaluate_moves(&mut ConnectFour::new(), SearchLimits::new())
+            .unwrap();
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let expected = rival
+            .evaluate_moves(&mut ConnectFour::new(), SearchLimits::new().with_depth(8))
+            .unwrap();
+
+        assert_eq!(evaluated, expected);
+    }
+
+    /// Stops the handle from another thread after a short while.
+    fn stop_soon(stop: &StopHandle) -> JoinHandle<()> {
+        let stop = stop.clone();
+
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            stop.stop();
+        })
+    }
+
+    #[test]
+    fn test_connect_four_cancelled() {
+        let stop = StopHandle::new();
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new().with_stop_handle(stop.clone());
+
+        let stopper = stop_soon(&stop);
+        let start = Instant::now();
+        assert_eq!(
+            rival.get_best(&mut ConnectFour::new(), 20),
+            Err(RivalError::Cancelled)
+        );
+        assert!(
+            start.elapsed() < Duration::from_secs(1),
+            "{:?}",
+            start.elapsed()
+        );
+        stopper.join().unwrap();
+
+        // Searches remain cancelled until the handle is reset
+        assert_eq!(
+            rival.get_best(&mut ConnectFour::new(), 1),
+            Err(RivalError::Cancelled)
+        );
+        stop.reset();
+
+        let mut game = ConnectFour::new();
+        for m in [0, 3, 0, 3, 6, 3] {
+            game.play(&m);
+        }
+        assert_eq!(rival.get_best(&mut game, 4), Ok(3), "{game}");
+    }
+
+    #[test]
+    fn test_connect_four_cancelled_within() {
+        let stop = StopHandle::new();
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new().with_stop_handle(stop.clone());
+
+        let stopper = stop_soon(&stop);
+        let start = Instant::now();
+        assert_eq!(
+            rival.get_best_within(&mut ConnectFour::new(), Duration::from_secs(60)),
+            Err(RivalError::Cancelled)
+        );
+        assert!(
+            start.elapsed() < Duration::from_secs(1),
+            "{:?}",
+            start.elapsed()
+        );
+        stopper.join().unwrap();
+    }
+
+    #[test]
+    fn test_connect_four_mtdf_vs_principal_variation() {
+        let mut pvs: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+        let mut mtdf: Rival<_, MtdF, 2, CAP> = Rival::new();
+
+        assert_eq!(
+            mtdf.get_best(&mut ConnectFour::new(), 7),
+            pvs.get_best(&mut ConnectFour::new(), 7)
+        );
+    }
+
+    #[bench]
+    fn bench_connect_four_negamax(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+            rival.get_best(&mut ConnectFour::new(), 7).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_connect_four_principal_variation(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Negamax, 2, CAP> =
+                Rival::with_strategy(Negamax::new().with_principal_variation(true));
+            rival.get_best(&mut ConnectFour::new(), 7).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_connect_four_mtdf(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+            rival.get_best(&mut ConnectFour::new(), 7).unwrap();
+        });
+    }
+}
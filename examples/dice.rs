@@ -0,0 +1,290 @@
+#![cfg_attr(test, feature(test))]
+
+use std::fmt::{Debug, Display, Formatter};
+
+use rival::{
+    ChanceMoves, CloneCacheKey, EvaluateZeroSum, LazyZobristHash, Moves, PlayClone, Value,
+};
+
+/// Number of sides of the die.
+pub const SIDES: u8 = 6;
+
+/// Value of winning the game.
+pub const WIN: Value = 100;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Action {
+    /// Collect two points.
+    Safe,
+    /// Roll the die instead.
+    Gamble,
+    /// The outcome of a roll: one loses the penalty, anything else is
+    /// collected.
+    Roll(u8),
+}
+
+/// Two players take turns either collecting two points, or rolling a die and
+/// collecting the number of eyes, unless a one is rolled, in which case they
+/// lose points instead. After a fixed number of turns, the player with the most
+/// points wins.
+///
+/// Only winning matters: a win is worth [`WIN`], while the point difference
+/// serves as an estimate for unfinished games.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Dice {
+    turn: usize,
+    turns: u8,
+    rolling: bool,
+    penalty: Value,
+    scores: [Value; 2],
+    bounded: bool,
+}
+
+impl Dice {
+    pub fn new(turns: u8, penalty: Value) -> Self {
+        Dice {
+            turn: 0,
+            turns,
+            rolling: false,
+            penalty,
+            scores: [0; 2],
+            bounded: true,
+        }
+    }
+
+    /// Hides the bounds on the value from the search algorithm, disabling
+    /// pruning at chance nodes.
+    pub fn unbounded(mut self) -> Self {
+        self.bounded = false;
+        self
+    }
+
+    /// Starts the game with the given scores instead of zero.
+    pub fn with_scores(mut self, scores: [Value; 2]) -> Self {
+        self.scores = scores;
+        self
+    }
+
+    pub fn scores(&self) -> [Value; 2] {
+        self.scores
+    }
+
+    fn end_turn(&mut self, points: Value) {
+        self.scores[self.turn] += points;
+        self.turn = 1 - self.turn;
+        self.turns -= 1;
+        self.rolling = false;
+    }
+}
+
+impl EvaluateZeroSum for Dice {
+    type Score = Value;
+
+    fn min_turn(&self) -> bool {
+        self.turn == 1
+    }
+
+    fn evaluate(&self) -> Value {
+        let difference = self.scores[0] - self.scores[1];
+
+        if self.turns == 0 {
+            difference.signum() * WIN
+        } else {
+            difference.clamp(1 - WIN, WIN - 1)
+        }
+    }
+
+    fn max_value(&self) -> Option<Value> {
+        self.bounded.then_some(WIN)
+    }
+
+    fn is_win(&self) -> Option<bool> {
+        let difference = self.scores[0] - self.scores[1];
+
+        (self.turns == 0 && difference != 0).then_some(difference > 0)
+    }
+}
+
+impl Moves for Dice {
+    type Move = Action;
+    type Iter<'a> = <Vec<Self::Move> as IntoIterator>::IntoIter;
+
+    fn moves(&self) -> Self::Iter<'static> {
+        if self.turns == 0 {
+            Vec::new().into_iter()
+        } else if self.rolling {
+            (1..=SIDES)
+                .map(Action::Roll)
+                .collect::<Vec<_>>()
+                .into_iter()
+        } else {
+            vec![Action::Safe, Action::Gamble].into_iter()
+        }
+    }
+}
+
+impl ChanceMoves for Dice {
+    type Outcomes<'a> = <Vec<(Self::Move, f64)> as IntoIterator>::IntoIter;
+
+    fn outcomes(&self) -> Option<Self::Outcomes<'_>> {
+        self.rolling.then(|| {
+            self.moves()
+                .map(|m| (m, 1.0 / f64::from(SIDES)))
+                .collect::<Vec<_>>()
+                .into_iter()
+        })
+    }
+}
+
+impl PlayClone for Dice {
+    fn play(&mut self, m: &Self::Move) {
+        match *m {
+            Action::Safe => self.end_turn(2),
+            Action::Gamble => self.rolling = true,
+            Action::Roll(1) => self.end_turn(-self.penalty),
+            Action::Roll(eyes) => self.end_turn(Value::from(eyes)),
+        }
+    }
+}
+
+impl LazyZobristHash for Dice {}
+
+impl CloneCacheKey for Dice {}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} turns left (player {} {}, scores {:?})",
+            self.turns,
+            self.turn,
+            if self.rolling { "rolling" } else { "to move" },
+            self.scores
+        )
+    }
+}
+
+impl Debug for Dice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate test;
+
+    use rival::{ChanceMoves, EvaluateZeroSum, Expectimax, Moves, PlayClone, Rival};
+    use test::Bencher;
+
+    use crate::{Action, Dice, SIDES, WIN};
+
+    /// Capacity of the transposition table of computer players in these tests.
+    const CAP: usize = 2000;
+
+    #[test]
+    fn test_dice_expectimax_gamble() {
+        // Collecting two points would lose, while rolling at least a three does not
+        let mut game = Dice::new(1, 4).with_scores([0, 3]);
+        let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 1), Ok(Action::Gamble), "{game}");
+    }
+
+    #[test]
+    fn test_dice_expectimax_safe() {
+        // Collecting two points always wins, while rolling a one would lose
+        let mut game = Dice::new(1, 4).with_scores([0, 1]);
+        let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.get_best(&mut game, 1), Ok(Action::Safe), "{game}");
+    }
+
+    #[test]
+    fn test_dice_expectimax_best_moves() {
+        // Rolling still wins unless it is a one, but is worth less than winning for sure
+        let mut game = Dice::new(1, 4).with_scores([0, 1]);
+        let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+        let ranked = rival.get_best_moves(&mut game, 1, 2).unwrap();
+
+        assert_eq!(ranked.len(), 2, "{game}");
+        assert_eq!(ranked[0], (Action::Safe, WIN, vec![Action::Safe]), "{game}");
+        assert_eq!(ranked[1].0, Action::Gamble, "{game}");
+        assert!(0 < ranked[1].1 && ranked[1].1 < WIN, "{:?}", ranked);
+    }
+
+    #[test]
+    fn test_dice_expectimax_second_player() {
+        // After the first player plays safe, the second player can only tie by rolling a six
+        let mut game = Dice::new(2, 4).with_scores([4, 0]);
+        let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+
+        assert_eq!(rival.play(&mut game, 2), Ok(()), "{game}");
+        assert_eq!(game.scores(), [6, 0]);
+        assert_eq!(rival.get_best(&mut game, 1), Ok(Action::Gamble), "{game}");
+    }
+
+    #[test]
+    fn test_dice_expectimax_full_game() {
+        let mut game = Dice::new(8, 4);
+        let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+        let mut rolls = (1..=SIDES).cycle();
+
+        while game.moves().len() > 0 {
+            if game.outcomes().is_some() {
+                game.play(&Action::Roll(rolls.next().unwrap()));
+            } else {
+                assert_eq!(rival.play(&mut game, 8), Ok(()), "{game}");
+            }
+        }
+
+        assert!([-WIN, 0, WIN].contains(&game.evaluate()), "{}", game);
+    }
+
+    #[test]
+    fn test_dice_expectimax_pruning() {
+        let mut plain: Rival<_, Expectimax, 2, CAP> = Rival::new();
+        let plain_best = plain.get_best(&mut Dice::new(6, 4).unbounded(), 6);
+
+        let mut star1: Rival<_, Expectimax, 2, CAP> = Rival::new();
+        let star1_best = star1.get_best(&mut Dice::new(6, 4), 6);
+
+        let mut star2: Rival<_, Expectimax, 2, CAP> =
+            Rival::with_strategy(Expectimax::new().with_probing(true));
+        let star2_best = star2.get_best(&mut Dice::new(6, 4), 6);
+
+        assert_eq!(plain_best, star1_best);
+        assert_eq!(plain_best, star2_best);
+        assert!(
+            star1.nodes() < plain.nodes(),
+            "{} >= {}",
+            star1.nodes(),
+            plain.nodes()
+        );
+    }
+
+    #[bench]
+    fn bench_dice_expectimax(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+            rival.get_best(&mut Dice::new(6, 4).unbounded(), 6).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_dice_expectimax_star1(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Expectimax, 2, CAP> = Rival::new();
+            rival.get_best(&mut Dice::new(6, 4), 6).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_dice_expectimax_star2(bencher: &mut Bencher) {
+        bencher.iter(|| {
+            let mut rival: Rival<_, Expectimax, 2, CAP> =
+                Rival::with_strategy(Expectimax::new().with_probing(true));
+            rival.get_best(&mut Dice::new(6, 4), 6).unwrap();
+        });
+    }
+}
@@ -1,4 +1,4 @@
-#![feature(test)]
+#![cfg_attr(test, feature(test))]
 
 use std::{
     fmt::{Debug, Display, Formatter},
@@ -8,13 +8,13 @@ use std::{
 use rival::{CloneCacheKey, EvaluateZeroSum, LazyZobristHash, Moves, PlayClone, Value};
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
-enum Symbol {
+pub enum Symbol {
     X,
     O,
 }
 
 impl Symbol {
-    fn value(self) -> i16 {
+    pub fn value(self) -> i16 {
         match self {
             Symbol::X => -1,
             Symbol::O => 1,
@@ -49,7 +49,7 @@ impl Debug for Symbol {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
-struct TicTacToe {
+pub struct TicTacToe {
     turn: Symbol,
     grid: [[Option<Symbol>; 3]; 3],
 }
@@ -63,6 +63,12 @@ impl TicTacToe {
     }
 }
 
+impl Default for TicTacToe {
+    fn default() -> Self {
+        TicTacToe::new()
+    }
+}
+
 impl Index<(usize, usize)> for TicTacToe {
     type Output = Option<Symbol>;
 
@@ -78,6 +84,8 @@ impl IndexMut<(usize, usize)> for TicTacToe {
 }
 
 impl EvaluateZeroSum for TicTacToe {
+    type Score = Value;
+
     fn min_turn(&self) -> bool {
         self.turn == Symbol::X
     }
@@ -106,6 +114,13 @@ impl EvaluateZeroSum for TicTacToe {
             .map(|symbol| symbol.value())
             .unwrap_or(0)
     }
+
+    fn is_win(&self) -> Option<bool> {
+        match self.evaluate() {
+            0 => None,
+            value => Some(value > 0),
+        }
+    }
 }
 
 impl Moves for TicTacToe {
@@ -193,7 +208,13 @@ impl Debug for TicTacToe {
 mod tests {
     extern crate test;
 
-    use rival::{EvaluateZeroSum, Moves, Negamax, PlayClone, Rival};
+    use rival::{
+        BestReply, ChanceMoves, CloneCacheKey, EvaluateZeroSum, Expectimax, LazyZobristHash, MaxN,
+        Mcts, Moves, MtdF, Negamax, NegamaxParams, NullMove, OrderedFloat, Paranoid, PlayClone,
+        Rival, Score, SearchLimits, Strategy, Value, WithNullMoves,
+    };
+    use std::{iter::Empty, time::Duration};
+
     use test::Bencher;
 
     use crate::TicTacToe;
@@ -201,32 +222,286 @@ mod tests {
     /// Capacity of the transposition table of computer players in these tests.
     const CAP: usize = 2000;
 
-    // #[test]
-    // fn test_tictactoe_maxn_tie() {
-    //     let mut game = TicTacToe::new();
-    //     let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
-    //
-    //     for _ in 0..9 {
-    //         assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
-    //     }
-    //
-    //     assert_eq!(game.moves().len(), 0);
-    //     assert_eq!(game.evaluate(), 0);
-    // }
-
-    // #[test]
-    // fn test_tictactoe_maxn_best_move() {
-    //     let mut game = TicTacToe::new();
-    //
-    //     game.play(&(0, 0));
-    //     game.play(&(1, 0));
-    //     game.play(&(0, 1));
-    //
-    //     let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
-    //     assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
-    //
-    //     assert_ne!(game[(0, 2)], None);
-    // }
+    /// Capacity of a transposition table too small to make a difference, to
+    /// compare the number of states different searches visit on their own.
+    const TINY_CAP: usize = 1;
+
+    /// Tic-tac-toe scored with floating-point numbers, worth half a point for
+    /// a win.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    struct Halves(TicTacToe);
+
+    impl EvaluateZeroSum for Halves {
+        type Score = OrderedFloat;
+
+        fn min_turn(&self) -> bool {
+            self.0.min_turn()
+        }
+
+        fn evaluate(&self) -> Self::Score {
+            OrderedFloat(f64::from(self.0.evaluate()) / 2.0)
+        }
+
+        fn max_value(&self) -> Option<Self::Score> {
+            Some(OrderedFloat(0.5))
+        }
+
+        fn is_win(&self) -> Option<bool> {
+            self.0.is_win()
+        }
+    }
+
+    impl Moves for Halves {
+        type Move = (usize, usize);
+        type Iter<'a> = <TicTacToe as Moves>::Iter<'a>;
+
+        fn moves(&self) -> Self::Iter<'static> {
+            self.0.moves()
+        }
+    }
+
+    impl PlayClone for Halves {
+        fn play(&mut self, m: &Self::Move) {
+            PlayClone::play(&mut self.0, m);
+        }
+    }
+
+    impl LazyZobristHash for Halves {}
+
+    impl CloneCacheKey for Halves {}
+
+    /// Tic-tac-toe in which the player to move is not quiet while they can
+    /// complete a line, and the moves that do are the only noisy moves. Moves
+    /// that let the opponent complete a line are extended.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    struct Tactical(TicTacToe);
+
+    impl Tactical {
+        fn winning_moves(&self) -> Vec<(usize, usize)> {
+            self.0
+                .moves()
+                .filter(|m| {
+                    let mut next = self.0;
+                    PlayClone::play(&mut next, m);
+                    next.evaluate() != 0
+                })
+                .collect()
+        }
+    }
+
+    impl EvaluateZeroSum for Tactical {
+        type Score = Value;
+
+        fn min_turn(&self) -> bool {
+            self.0.min_turn()
+        }
+
+        fn evaluate(&self) -> Self::Score {
+            self.0.evaluate()
+        }
+
+        fn quiet(&self) -> bool {
+            self.winning_moves().is_empty()
+        }
+    }
+
+    impl Moves for Tactical {
+        type Move = (usize, usize);
+        type Iter<'a> = <TicTacToe as Moves>::Iter<'a>;
+
+        fn moves(&self) -> Self::Iter<'static> {
+            self.0.moves()
+        }
+
+        fn noisy_moves(&self) -> Self::Iter<'static> {
+            self.winning_moves().into_iter()
+        }
+
+        fn extend(&self, m: &Self::Move) -> u8 {
+            let mut next = *self;
+            PlayClone::play(&mut next, m);
+            u8::from(!next.winning_moves().is_empty())
+        }
+    }
+
+    impl PlayClone for Tactical {
+        fn play(&mut self, m: &Self::Move) {
+            PlayClone::play(&mut self.0, m);
+        }
+    }
+
+    impl LazyZobristHash for Tactical {}
+
+    impl CloneCacheKey for Tactical {}
+
+    /// Tic-tac-toe that is never quiet, in which every move is noisy.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    struct Restless(TicTacToe);
+
+    impl EvaluateZeroSum for Restless {
+        type Score = Value;
+
+        fn min_turn(&self) -> bool {
+            self.0.min_turn()
+        }
+
+        fn evaluate(&self) -> Self::Score {
+            self.0.evaluate()
+        }
+
+        fn quiet(&self) -> bool {
+            false
+        }
+    }
+
+    impl Moves for Restless {
+        type Move = (usize, usize);
+        type Iter<'a> = <TicTacToe as Moves>::Iter<'a>;
+
+        fn moves(&self) -> Self::Iter<'static> {
+            self.0.moves()
+        }
+    }
+
+    impl ChanceMoves for Restless {
+        type Outcomes<'a> = Empty<((usize, usize), f64)>;
+
+        fn outcomes(&self) -> Option<Self::Outcomes<'_>> {
+            None
+        }
+    }
+
+    impl PlayClone for Restless {
+        fn play(&mut self, m: &Self::Move) {
+            PlayClone::play(&mut self.0, m);
+        }
+    }
+
+    impl LazyZobristHash for Restless {}
+
+    impl CloneCacheKey for Restless {}
+
+    /// Tic-tac-toe that can pass the turn, but never considers that safe.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    struct Unpassable(TicTacToe);
+
+    impl EvaluateZeroSum for Unpassable {
+        type Score = Value;
+
+        fn min_turn(&self) -> bool {
+            self.0.min_turn()
+        }
+
+        fn evaluate(&self) -> Self::Score {
+            self.0.evaluate()
+        }
+    }
+
+    impl Moves for Unpassable {
+        type Move = (usize, usize);
+        type Iter<'a> = <TicTacToe as Moves>::Iter<'a>;
+
+        fn moves(&self) -> Self::Iter<'static> {
+            self.0.moves()
+        }
+    }
+
+    impl PlayClone for Unpassable {
+        fn play(&mut self, m: &Self::Move) {
+            PlayClone::play(&mut self.0, m);
+        }
+    }
+
+    impl NullMove for Unpassable {
+        fn null_move_safe(&self) -> bool {
+            false
+        }
+
+        fn play_null(&mut self) -> Self {
+            let clone = *self;
+            self.0.turn = !self.0.turn;
+            clone
+        }
+    }
+
+    impl LazyZobristHash for Unpassable {}
+
+    impl CloneCacheKey for Unpassable {}
+
+    /// Tic-tac-toe in which a win evaluates to the highest possible score, and
+    /// in which the turn can be passed.
+    #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+    struct Extreme(TicTacToe);
+
+    impl EvaluateZeroSum for Extreme {
+        type Score = Value;
+
+        fn min_turn(&self) -> bool {
+            self.0.min_turn()
+        }
+
+        fn evaluate(&self) -> Self::Score {
+            self.0.evaluate() * Value::MAX
+        }
+    }
+
+    impl Moves for Extreme {
+        type Move = (usize, usize);
+        type Iter<'a> = <TicTacToe as Moves>::Iter<'a>;
+
+        fn moves(&self) -> Self::Iter<'static> {
+            self.0.moves()
+        }
+    }
+
+    impl PlayClone for Extreme {
+        fn play(&mut self, m: &Self::Move) {
+            PlayClone::play(&mut self.0, m);
+        }
+    }
+
+    impl NullMove for Extreme {
+        fn null_move_safe(&self) -> bool {
+            true
+        }
+
+        fn play_null(&mut self) -> Self {
+            let clone = *self;
+            self.0.turn = !self.0.turn;
+            clone
+        }
+    }
+
+    impl LazyZobristHash for Extreme {}
+
+    impl CloneCacheKey for Extreme {}
+
+    #[test]
+    fn test_tictactoe_maxn_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_maxn_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
 
     #[test]
     fn test_tictactoe_negamax_tie() {
@@ -255,34 +530,448 @@ mod tests {
         assert_ne!(game[(0, 2)], None);
     }
 
-    // #[test]
-    // fn test_tictactoe_maxn_vs_negamax_tie() {
-    //     let mut game = TicTacToe::new();
-    //     let mut a: Rival<_, Negamax, 2, CAP> = Rival::new();
-    //     let mut b: Rival<_, MaxN, 2, CAP> = Rival::new();
-    //
-    //     for _ in 0..4 {
-    //         assert_eq!(a.play(&mut game, 9), Ok(()), "{game}");
-    //         assert_eq!(b.play(&mut game, 9), Ok(()), "{game}");
-    //     }
-    //     assert_eq!(a.play(&mut game, 9), Ok(()), "{game}");
-    //
-    //     assert_eq!(game.moves().len(), 0);
-    //     assert_eq!(game.evaluate(), 0);
-    // }
-
-    // #[bench]
-    // fn bench_tictactoe_maxn(bencher: &mut Bencher) {
-    //     let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
-    //
-    //     bencher.iter(|| {
-    //         let mut game = TicTacToe::new();
-    //
-    //         for _ in 0..9 {
-    //             rival.play(&mut game, 9).unwrap();
-    //         }
-    //     });
-    // }
+    #[test]
+    fn test_tictactoe_negamax_win_in() {
+        // Without a maximum value, a win is still told apart from a good evaluation
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+        game.play(&(1, 1));
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let report = rival
+            .analyze(&mut game, SearchLimits::new().with_depth(5))
+            .unwrap();
+
+        assert_eq!(report.best, (0, 2), "{game}");
+        assert_eq!(report.score, Some(Value::FORCED_WIN - 1), "{game}");
+        assert_eq!(report.win_in, Some(1), "{game}");
+    }
+
+    #[test]
+    fn test_tictactoe_negamax_extreme_scores() {
+        // Null windows around the highest and lowest scores must not overflow, and a lost game
+        // still has moves to play
+        fn assert_lost<S: Strategy<Extreme, 2, CAP>>(strategy: S) {
+            let mut game = Extreme(TicTacToe::new());
+
+            game.play(&(0, 0));
+            game.play(&(1, 0));
+            game.play(&(0, 1));
+
+            let mut rival = Rival::with_strategy(strategy);
+            while game.moves().len() > 0 {
+                assert_eq!(rival.play(&mut game, 9), Ok(()), "{}", game.0);
+            }
+
+            assert_eq!(game.evaluate(), Value::MAX, "{}", game.0);
+        }
+
+        assert_lost(
+            Negamax::new()
+                .with_principal_variation(true)
+                .with_null_moves(),
+        );
+        assert_lost(MtdF::new());
+    }
+
+    #[test]
+    fn test_tictactoe_negamax_within() {
+        // The whole game is searched long before the timeout, which must not overflow the depth
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        assert_eq!(
+            rival.play_within(&mut game, Duration::from_secs(10)),
+            Ok(()),
+            "{game}"
+        );
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_negamax_best_moves() {
+        // Every first move leads to a tie, and every move is ranked once
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let ranked = rival.get_best_moves(&mut TicTacToe::new(), 9, 10).unwrap();
+
+        let mut moves: Vec<_> = ranked.iter().map(|(m, _, _)| *m).collect();
+        moves.sort_unstable();
+        moves.dedup();
+        assert_eq!(moves.len(), 9);
+        assert!(
+            ranked.iter().all(|(_, score, _)| *score == 0),
+            "{:?}",
+            ranked
+        );
+    }
+
+    #[test]
+    fn test_tictactoe_negamax_evaluate_moves() {
+        // Only blocking the opponent avoids losing, and it threatens two wins at once
+        let mut game = TicTacToe::new();
+        for m in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let evaluated = rival
+            .evaluate_moves(&mut game, SearchLimits::new().with_depth(9))
+            .unwrap();
+
+        assert_eq!(
+            evaluated.iter().map(|(m, _)| *m).collect::<Vec<_>>(),
+            game.moves().collect::<Vec<_>>()
+        );
+        assert!(
+            evaluated
+                .iter()
+                .all(|&(m, score)| (score > 0) == (m == (2, 0))),
+            "{:?}",
+            evaluated
+        );
+    }
+
+    #[test]
+    fn test_tictactoe_negamax_float_scores() {
+        // Blocking the opponent threatens two wins at once, so that the game is won in three moves
+        let mut game = Halves(TicTacToe::new());
+        for m in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            game.play(&m);
+        }
+
+        let mut rival: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let report = rival
+            .analyze(&mut game, SearchLimits::new().with_depth(9))
+            .unwrap();
+
+        assert_eq!(report.best, (2, 0));
+        assert_eq!(report.score, Some(OrderedFloat::forced_win(3)));
+        assert_eq!(report.win_in, Some(3));
+
+        // Any other move loses on the next one
+        let evaluated = rival
+            .evaluate_moves(&mut game, SearchLimits::new().with_depth(9))
+            .unwrap();
+        for (m, score) in evaluated {
+            let expected = if m == (2, 0) { 3 } else { -2 };
+            assert_eq!(score.win_in(), Some(expected), "{:?}", m);
+        }
+    }
+
+    #[test]
+    fn test_tictactoe_quiescence() {
+        // Searching one move ahead only sees the threat to block by looking past that move
+        let mut game = Tactical(TicTacToe::new());
+        for m in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            game.play(&m);
+        }
+
+        let params = NegamaxParams {
+            quiescence_depth: 0,
+            max_extension: 0,
+            ..NegamaxParams::default()
+        };
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_params(params));
+        assert_ne!(rival.get_best(&mut game, 1), Ok((2, 0)));
+
+        let params = NegamaxParams {
+            quiescence_depth: 8,
+            ..params
+        };
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_params(params));
+        assert_eq!(rival.get_best(&mut game, 1), Ok((2, 0)));
+
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+        assert_eq!(rival.get_best(&mut game, 1), Ok((2, 0)));
+
+        let mut rival: Rival<_, Paranoid, 2, CAP> = Rival::new();
+        assert_eq!(rival.get_best(&mut game, 1), Ok((2, 0)));
+    }
+
+    #[test]
+    fn test_tictactoe_quiescence_depth() {
+        // Searching one move ahead in a game that is never quiet stops two moves further, at
+        // 1 + 9 * (1 + 8 + 8 * 7) states, instead of searching the whole game
+        let max_nodes = 586;
+
+        let mut rival: Rival<_, MaxN, 2, CAP> =
+            Rival::with_strategy(MaxN::new().with_quiescence_depth(2));
+        assert!(rival.get_best(&mut Restless(TicTacToe::new()), 1).is_ok());
+        assert!(rival.nodes() <= max_nodes, "{}", rival.nodes());
+
+        let mut rival: Rival<_, BestReply, 2, CAP> =
+            Rival::with_strategy(BestReply::new().with_quiescence_depth(2));
+        assert!(rival.get_best(&mut Restless(TicTacToe::new()), 1).is_ok());
+        assert!(rival.nodes() <= max_nodes, "{}", rival.nodes());
+
+        let mut rival: Rival<_, Expectimax, 2, CAP> =
+            Rival::with_strategy(Expectimax::new().with_quiescence_depth(2));
+        assert!(rival.get_best(&mut Restless(TicTacToe::new()), 1).is_ok());
+        assert!(rival.nodes() <= max_nodes, "{}", rival.nodes());
+
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_quiescence_depth(2));
+        assert!(rival.get_best(&mut Restless(TicTacToe::new()), 1).is_ok());
+        assert!(rival.nodes() <= max_nodes, "{}", rival.nodes());
+    }
+
+    #[test]
+    fn test_tictactoe_null_move_unsafe() {
+        // Where passing is not safe, null-move pruning is skipped
+        let mut unpassable: Rival<_, Negamax<WithNullMoves>, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_null_moves());
+        let unpassable_best = unpassable.get_best(&mut Unpassable(TicTacToe::new()), 6);
+
+        let mut plain: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let plain_best = plain.get_best(&mut TicTacToe::new(), 6);
+
+        assert_eq!(unpassable_best, plain_best);
+        assert_eq!(unpassable.nodes(), plain.nodes());
+    }
+
+    #[test]
+    fn test_tictactoe_extensions() {
+        // Searching one move ahead only sees the threat to block by extending the moves that ignore it
+        let mut game = Tactical(TicTacToe::new());
+        for m in [(0, 0), (1, 1), (2, 2), (0, 2)] {
+            game.play(&m);
+        }
+
+        let params = NegamaxParams {
+            quiescence_depth: 0,
+            max_extension: 0,
+            ..NegamaxParams::default()
+        };
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_params(params));
+        assert_ne!(rival.get_best(&mut game, 1), Ok((2, 0)));
+
+        let params = NegamaxParams {
+            max_extension: 1,
+            ..params
+        };
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_params(params));
+        assert_eq!(rival.get_best(&mut game, 1), Ok((2, 0)));
+    }
+
+    #[test]
+    fn test_tictactoe_principal_variation_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_principal_variation_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_principal_variation_nodes() {
+        let mut plain: Rival<_, Negamax, 2, TINY_CAP> = Rival::new();
+        let plain_best = plain.get_best(&mut TicTacToe::new(), 9);
+
+        let mut pvs: Rival<_, Negamax, 2, TINY_CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+        let pvs_best = pvs.get_best(&mut TicTacToe::new(), 9);
+
+        assert_eq!(plain_best, pvs_best);
+        assert!(
+            pvs.nodes() < plain.nodes(),
+            "{} >= {}",
+            pvs.nodes(),
+            plain.nodes()
+        );
+    }
+
+    #[test]
+    fn test_tictactoe_mtdf_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_mtdf_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, MtdF, 2, CAP> = Rival::new();
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_paranoid_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, Paranoid, 2, CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_paranoid_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, Paranoid, 2, CAP> = Rival::new();
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_best_reply_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, BestReply, 2, CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_best_reply_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, BestReply, 2, CAP> = Rival::new();
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_mcts_tie() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, Mcts, 2, CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_mcts_best_move() {
+        let mut game = TicTacToe::new();
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, Mcts, 2, CAP> = Rival::new();
+        assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_ne!(game[(0, 2)], None);
+    }
+
+    #[test]
+    fn test_tictactoe_mcts_small_cache() {
+        let mut game = TicTacToe::new();
+        let mut rival: Rival<_, Mcts, 2, TINY_CAP> = Rival::new();
+
+        for _ in 0..9 {
+            assert_eq!(rival.play(&mut game, 9), Ok(()), "{game}");
+        }
+
+        assert_eq!(game.moves().len(), 0);
+    }
+
+    #[test]
+    fn test_tictactoe_mcts_float_scores() {
+        let mut game = Halves(TicTacToe::new());
+
+        game.play(&(0, 0));
+        game.play(&(1, 0));
+        game.play(&(0, 1));
+
+        let mut rival: Rival<_, Mcts, 2, CAP> = Rival::new();
+        assert_eq!(rival.get_best(&mut game, 9), Ok((0, 2)), "{}", game.0);
+    }
+
+    #[test]
+    fn test_tictactoe_maxn_vs_negamax_tie() {
+        let mut game = TicTacToe::new();
+        let mut a: Rival<_, Negamax, 2, CAP> = Rival::new();
+        let mut b: Rival<_, MaxN, 2, CAP> = Rival::new();
+
+        for _ in 0..4 {
+            assert_eq!(a.play(&mut game, 9), Ok(()), "{game}");
+            assert_eq!(b.play(&mut game, 9), Ok(()), "{game}");
+        }
+        assert_eq!(a.play(&mut game, 9), Ok(()), "{game}");
+
+        assert_eq!(game.moves().len(), 0);
+        assert_eq!(game.evaluate(), 0);
+    }
+
+    #[bench]
+    fn bench_tictactoe_maxn(bencher: &mut Bencher) {
+        let mut rival: Rival<_, MaxN, 2, CAP> = Rival::new();
+
+        bencher.iter(|| {
+            let mut game = TicTacToe::new();
+
+            for _ in 0..9 {
+                rival.play(&mut game, 9).unwrap();
+            }
+        });
+    }
 
     #[bench]
     fn bench_tictactoe_negamax(bencher: &mut Bencher) {
@@ -296,4 +985,18 @@ mod tests {
             }
         });
     }
+
+    #[bench]
+    fn bench_tictactoe_principal_variation(bencher: &mut Bencher) {
+        let mut rival: Rival<_, Negamax, 2, CAP> =
+            Rival::with_strategy(Negamax::new().with_principal_variation(true));
+
+        bencher.iter(|| {
+            let mut game = TicTacToe::new();
+
+            for _ in 0..9 {
+                rival.play(&mut game, 9).unwrap();
+            }
+        });
+    }
 }
@@ -1,19 +1,36 @@
 use std::{
+    cell::Cell,
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
 };
 
+type Entry<K, V> = Option<(K, V)>;
+
 #[derive(Debug)]
 pub struct TranspositionTable<G: CacheKey, V, const CAP: usize> {
-    items: Box<[Option<(G::Key, V)>]>,
+    items: Box<[Entry<G::Key, V>]>,
+    probes: Cell<u64>,
+    hits: Cell<u64>,
 }
 
 impl<G: CacheKey, V, const CAP: usize> TranspositionTable<G, V, CAP> {
     pub fn new() -> Self {
         TranspositionTable {
-            items: Box::new([(); CAP].map(|_| None)),
+            items: (0..CAP).map(|_| None).collect(),
+            probes: Cell::new(0),
+            hits: Cell::new(0),
         }
     }
+
+    /// The total number of lookups so far.
+    pub(crate) fn probes(&self) -> u64 {
+        self.probes.get()
+    }
+
+    /// The total number of lookups so far that found an entry.
+    pub(crate) fn hits(&self) -> u64 {
+        self.hits.get()
+    }
 }
 
 impl<G: ZobristHash + CacheKey, V, const CAP: usize> TranspositionTable<G, V, CAP> {
@@ -24,6 +41,19 @@ impl<G: ZobristHash + CacheKey, V, const CAP: usize> TranspositionTable<G, V, CA
     }
 
     pub fn get(&self, state: &G) -> Option<&V> {
+        let value = self.peek(state);
+        self.probes.set(self.probes.get() + 1);
+        if value.is_some() {
+            self.hits.set(self.hits.get() + 1);
+        }
+
+        value
+    }
+
+    /// Like [`get`], but without counting the lookup.
+    ///
+    /// [`get`]: Self::get
+    pub(crate) fn peek(&self, state: &G) -> Option<&V> {
         let index = state.zobrist_hash() % CAP;
         match self.items[index].as_ref() {
             Some((old_key, value)) if state.cache_key() == *old_key => Some(value),
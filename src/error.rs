@@ -8,12 +8,17 @@ pub type RivalResult<T, E = RivalError> = Result<T, E>;
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum RivalError {
     NoMove,
+    /// The search was stopped through a [`StopHandle`].
+    ///
+    /// [`StopHandle`]: crate::StopHandle
+    Cancelled,
 }
 
 impl Display for RivalError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             RivalError::NoMove => write!(f, "no move possible"),
+            RivalError::Cancelled => write!(f, "search cancelled"),
         }
     }
 }
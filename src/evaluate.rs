@@ -1,3 +1,5 @@
+use crate::Score;
+
 pub type Value = i16;
 
 /// Describes how the search algorithm should estimate which player is doing
@@ -5,6 +7,9 @@ pub type Value = i16;
 /// number of players. The [`EvaluateZeroSum`] trait can be implemented instead
 /// for 2-player games.
 pub trait Evaluate<const N: usize> {
+    /// The type of the scores, usually [`Value`].
+    type Score: Score;
+
     /// Indicates whose turn it is. The values returned by [`evaluate`] should
     /// consistently return the score associated with the current player at this
     /// index.
@@ -14,7 +19,7 @@ pub trait Evaluate<const N: usize> {
 
     /// Evaluates the current state, returning the respective scores for each
     /// player. Higher is better.
-    fn evaluate(&self) -> [Value; N];
+    fn evaluate(&self) -> [Self::Score; N];
 
     /// Indicates whether the current state of the game is conclusive enough to
     /// stop searching here. If big changes are about to happen, such as a queen
@@ -25,6 +30,35 @@ pub trait Evaluate<const N: usize> {
     fn quiet(&self) -> bool {
         true
     }
+
+    /// The highest score a single player can possibly get, if known. Once a
+    /// player is guaranteed this score, the search algorithm can skip their
+    /// remaining moves, and [`Rival::search`] can stop searching deeper if
+    /// asked to.
+    ///
+    /// [`Rival::search`]: crate::Rival::search
+    fn max_score(&self) -> Option<Self::Score> {
+        None
+    }
+
+    /// The player who has won the game in the current state, if any.
+    /// [`Paranoid`] scores a win by how many moves it takes, as explained for
+    /// [`Score::FORCED_WIN`].
+    ///
+    /// [`Paranoid`]: crate::Paranoid
+    fn winner(&self) -> Option<usize> {
+        None
+    }
+
+    /// The highest possible sum of the scores of all players, if known. This
+    /// allows the search algorithm to skip moves that cannot influence the
+    /// choices made earlier in the game, at the cost of requiring [`evaluate`]
+    /// to never return a negative score.
+    ///
+    /// [`evaluate`]: Self::evaluate
+    fn max_sum(&self) -> Option<Self::Score> {
+        None
+    }
 }
 
 /// Describes how the search algorithm should estimate which player is doing
@@ -33,6 +67,9 @@ pub trait Evaluate<const N: usize> {
 /// than the more general [`Evaluate`] trait, but not applicable for all types
 /// of games.
 pub trait EvaluateZeroSum {
+    /// The type of the scores, usually [`Value`].
+    type Score: Score;
+
     /// Indicates whether the current player is trying to minimize the score.
     /// This should be reflected by [`evaluate`] returning a lower score if this
     /// player is doing well.
@@ -43,7 +80,7 @@ pub trait EvaluateZeroSum {
     /// Evaluates the current state, returning a high score if the maximizing
     /// player is winning, and a low score if the minimizing player is doing
     /// better.
-    fn evaluate(&self) -> Value;
+    fn evaluate(&self) -> Self::Score;
 
     /// Indicates whether the current state of the game is conclusive enough to
     /// stop searching here. If big changes are about to happen, such as a queen
@@ -54,14 +91,38 @@ pub trait EvaluateZeroSum {
     fn quiet(&self) -> bool {
         true
     }
+
+    /// The highest absolute value [`evaluate`] can return, if known. This
+    /// allows strategies that average over random events, such as
+    /// [`Expectimax`], to skip outcomes that cannot change the result. A player
+    /// who is guaranteed this value has won, so that [`Rival::search`] can stop
+    /// searching deeper if asked to.
+    ///
+    /// [`evaluate`]: Self::evaluate
+    /// [`Expectimax`]: crate::Expectimax
+    /// [`Rival::search`]: crate::Rival::search
+    fn max_value(&self) -> Option<Self::Score> {
+        None
+    }
+
+    /// Indicates whether either player has won the game in the current state:
+    /// `Some(true)` if the maximizing player has won, `Some(false)` if the
+    /// minimizing player has, and `None` if neither has. Strategies built on
+    /// alpha-beta search score a win by how many moves it takes, as explained
+    /// for [`Score::FORCED_WIN`], so that they prefer faster wins.
+    fn is_win(&self) -> Option<bool> {
+        None
+    }
 }
 
 impl<G: EvaluateZeroSum> Evaluate<2> for G {
+    type Score = G::Score;
+
     fn turn(&self) -> usize {
         self.min_turn() as usize
     }
 
-    fn evaluate(&self) -> [Value; 2] {
+    fn evaluate(&self) -> [Self::Score; 2] {
         let value = self.evaluate();
         [value, -value]
     }
@@ -69,4 +130,12 @@ impl<G: EvaluateZeroSum> Evaluate<2> for G {
     fn quiet(&self) -> bool {
         self.quiet()
     }
+
+    fn max_score(&self) -> Option<Self::Score> {
+        self.max_value()
+    }
+
+    fn winner(&self) -> Option<usize> {
+        self.is_win().map(|max| usize::from(!max))
+    }
 }
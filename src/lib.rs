@@ -1,15 +1,25 @@
 mod cache;
 mod error;
 mod evaluate;
+mod limits;
 mod moves;
 mod play;
+mod report;
 mod rival;
+mod score;
 mod search;
 
 pub use cache::{CacheKey, CloneCacheKey, LazyZobristHash, ZobristHash};
 pub use error::{RivalError, RivalResult};
 pub use evaluate::{Evaluate, EvaluateZeroSum, Value};
-pub use moves::Moves;
-pub use play::{Play, PlayClone};
-pub use rival::Rival;
-pub use search::{HasMin, Negamax, SearchResult, Strategy};
+pub use limits::SearchLimits;
+pub use moves::{ChanceMoves, Moves};
+pub use play::{NullMove, Play, PlayClone};
+pub use report::{RankedMove, SearchReport};
+pub use rival::{Rival, TimeManager};
+pub use score::{OrderedFloat, Score, MAX_PLY};
+pub use search::{
+    BestReply, Bound, Bounds, Expectimax, HasMin, Interrupt, MaxN, Mcts, MtdF, Negamax,
+    NegamaxParams, NoNullMoves, NullMoves, Paranoid, Playouts, SearchResult, StopHandle, Strategy,
+    WithNullMoves,
+};
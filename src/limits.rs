@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+/// When [`Rival::search`] should stop searching deeper. By default, the search
+/// only stops at the maximum depth.
+///
+/// [`Rival::search`]: crate::Rival::search
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SearchLimits {
+    pub(crate) depth: u8,
+    pub(crate) nodes: Option<u64>,
+    pub(crate) soft_time: Option<Duration>,
+    pub(crate) extended_soft_time: Option<Duration>,
+    pub(crate) hard_time: Option<Duration>,
+    pub(crate) stop_on_forced_win: bool,
+}
+
+impl SearchLimits {
+    pub fn new() -> Self {
+        SearchLimits {
+            depth: u8::MAX,
+            nodes: None,
+            soft_time: None,
+            extended_soft_time: None,
+            hard_time: None,
+            stop_on_forced_win: false,
+        }
+    }
+
+    /// Sets the depth of the last search. Defaults to [`u8::MAX`].
+    pub fn with_depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Interrupts the search once it has visited this many states in total.
+    /// Unlike time limits, this makes the outcome reproducible, as long as the
+    /// strategy keeps track of the states it visits.
+    pub fn with_nodes(mut self, nodes: u64) -> Self {
+        self.nodes = Some(nodes);
+        self
+    }
+
+    /// Prevents starting a deeper search after this much time has passed. The
+    /// search that is running at that moment is allowed to complete.
+    pub fn with_soft_time(mut self, soft_time: Duration) -> Self {
+        self.soft_time = Some(soft_time);
+        self
+    }
+
+    /// Replaces the soft time when the last search is unsettled: when it found a
+    /// different best move than the search before, or a lower score. Has no
+    /// effect without a soft time.
+    pub fn with_extended_soft_time(mut self, extended_soft_time: Duration) -> Self {
+        self.extended_soft_time = Some(extended_soft_time);
+        self
+    }
+
+    /// Interrupts the search once this much time has passed.
+    pub fn with_hard_time(mut self, hard_time: Duration) -> Self {
+        self.hard_time = Some(hard_time);
+        self
+    }
+
+    /// Sets whether to stop as soon as a search proves that the player to move
+    /// can force a win, as searching deeper cannot find anything better.
+    /// Defaults to `false`.
+    pub fn with_stop_on_forced_win(mut self, stop_on_forced_win: bool) -> Self {
+        self.stop_on_forced_win = stop_on_forced_win;
+        self
+    }
+
+    /// Indicates whether none of the limits has been set, so that only the
+    /// maximum depth stops the search.
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.depth == u8::MAX
+            && self.nodes.is_none()
+            && self.soft_time.is_none()
+            && self.hard_time.is_none()
+    }
+
+    /// The time after which the search is interrupted, if any.
+    pub fn hard_time(&self) -> Option<Duration> {
+        self.hard_time
+    }
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        SearchLimits::new()
+    }
+}
@@ -5,4 +5,74 @@ pub trait Moves {
         Self: 'a;
 
     fn moves(&self) -> Self::Iter<'_>;
+
+    /// Returns the moves that can change the evaluation a lot, such as
+    /// captures, which are the only moves searched past the maximum depth
+    /// while the state is not [`quiet`]. These should be among [`moves`]. By
+    /// default, all moves are noisy.
+    ///
+    /// [`quiet`]: crate::Evaluate::quiet
+    /// [`moves`]: Moves::moves
+    fn noisy_moves(&self) -> Self::Iter<'_> {
+        self.moves()
+    }
+
+    /// Estimates how promising a move is, so that searches can try the most
+    /// promising moves first. Moves with a higher priority are searched
+    /// earlier, while moves with equal priority are searched in the order
+    /// [`moves`] generates them. By default, all moves have the same priority.
+    /// Games can override this to search captures or threats first, without
+    /// changing the order of [`moves`].
+    ///
+    /// [`moves`]: Moves::moves
+    fn priority(&self, _m: &Self::Move) -> i32 {
+        0
+    }
+
+    /// How many moves deeper to search after a move than after the others,
+    /// such as a check or a move that leaves the opponent a single reply, so
+    /// that searches see where it leads. Strategies that support extensions,
+    /// such as [`Negamax`], limit how far a line can be extended in total. By
+    /// default, no move is extended.
+    ///
+    /// [`Negamax`]: crate::Negamax
+    fn extend(&self, _m: &Self::Move) -> u8 {
+        0
+    }
+
+    /// Chooses the move to play during a random playout of [`Mcts`], given a
+    /// random number. By default, every move is equally likely to be chosen.
+    /// Games can override this to make playouts more realistic, for example by
+    /// preferring moves that capture pieces.
+    ///
+    /// [`Mcts`]: crate::Mcts
+    fn playout_move(&self, random: u64) -> Option<Self::Move> {
+        let count = self.moves().count() as u64;
+
+        if count == 0 {
+            None
+        } else {
+            self.moves().nth((random % count) as usize)
+        }
+    }
+}
+
+/// Describes the random events of a game, such as rolling dice or drawing
+/// cards. A state in which such an event is about to happen is a chance node:
+/// instead of a player choosing a move, one of the outcomes is chosen at random.
+/// Outcomes are represented by moves, and are played like any other move.
+///
+/// Only strategies that support chance nodes, such as [`Expectimax`], take
+/// this trait into account.
+///
+/// [`Expectimax`]: crate::Expectimax
+pub trait ChanceMoves: Moves {
+    type Outcomes<'a>: Iterator<Item = (Self::Move, f64)>
+    where
+        Self: 'a;
+
+    /// Returns the possible outcomes of the random event that is about to
+    /// happen, paired with their probabilities, or `None` if a player is to
+    /// move instead. The probabilities should add up to 1.
+    fn outcomes(&self) -> Option<Self::Outcomes<'_>>;
 }
@@ -70,3 +70,35 @@ where
         *self = remember;
     }
 }
+
+/// Describes how to pass the turn to the next player, for games in which
+/// that is allowed, or at least a reasonable approximation of the worst the
+/// player to move can do. This lets [`Negamax`] skip moves that turn out to
+/// be good enough even after passing, also known as null-move pruning, once
+/// enabled with [`Negamax::with_null_moves`].
+///
+/// [`Negamax`]: crate::Negamax
+/// [`Negamax::with_null_moves`]: crate::Negamax::with_null_moves
+pub trait NullMove: Play {
+    /// Indicates whether passing the turn is safe in the current state. This
+    /// should be `false` in states where having to move is a disadvantage
+    /// (zugzwang), such as many endgames.
+    fn null_move_safe(&self) -> bool;
+
+    /// Passes the turn to the next player. Only called when
+    /// [`null_move_safe`] returns `true`. Returns data of type [`Remember`] to
+    /// help [`unplay_null`] the pass later.
+    ///
+    /// [`null_move_safe`]: Self::null_move_safe
+    /// [`Remember`]: Play::Remember
+    /// [`unplay_null`]: Self::unplay_null
+    fn play_null(&mut self) -> Self::Remember;
+
+    /// Describes how to undo passing the turn, like [`unplay`], which it
+    /// defaults to.
+    ///
+    /// [`unplay`]: Play::unplay
+    fn unplay_null(&mut self, remember: Self::Remember) {
+        self.unplay(remember);
+    }
+}
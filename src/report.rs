@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use crate::Value;
+
+/// A move found by [`Rival::get_best_moves`], with its score for the player to
+/// move and the principal variation that starts with it.
+///
+/// [`Rival::get_best_moves`]: crate::Rival::get_best_moves
+pub type RankedMove<M, V = Value> = (M, V, Vec<M>);
+
+/// What [`Rival::analyze`] found, and how much work it took to find it.
+///
+/// [`Rival::analyze`]: crate::Rival::analyze
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SearchReport<M, V = Value> {
+    /// The move the search considers best.
+    pub best: M,
+    /// The score of the best move for the player to move, as found by the last
+    /// search that completed, if the strategy can express it as a single
+    /// number.
+    pub score: Option<V>,
+    /// The number of moves in which the player to move can force a win, or
+    /// the negative number of moves in which the opponent can, if the last
+    /// search that completed proved either.
+    pub win_in: Option<i16>,
+    /// The depth of the last search that completed.
+    pub depth: u8,
+    /// The depth of the search that was still running when the report was
+    /// sent, if any. Progress reports during a search set this, while the
+    /// other fields still describe the last search that completed.
+    pub searching_depth: Option<u8>,
+    /// The moves the players are expected to make, starting with the best
+    /// move, as far as the transposition table remembers them.
+    pub principal_variation: Vec<M>,
+    /// The number of states visited by all searches together.
+    pub nodes: u64,
+    /// The time spent on all searches together.
+    pub elapsed: Duration,
+    /// The number of times a state was looked up in the transposition table.
+    pub cache_probes: u64,
+    /// The number of those lookups that found an entry for the state.
+    pub cache_hits: u64,
+}
+
+impl<M, V> SearchReport<M, V> {
+    pub fn nodes_per_second(&self) -> f64 {
+        if self.elapsed.is_zero() {
+            0.0
+        } else {
+            self.nodes as f64 / self.elapsed.as_secs_f64()
+        }
+    }
+
+    /// The fraction of lookups in the transposition table that found an entry.
+    pub fn hit_rate(&self) -> f64 {
+        if self.cache_probes == 0 {
+            0.0
+        } else {
+            self.cache_hits as f64 / self.cache_probes as f64
+        }
+    }
+}
@@ -1,23 +1,180 @@
 use std::{
-    fmt::Debug,
+    fmt::{Debug, Formatter},
     marker::PhantomData,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
     time::{Duration, Instant},
 };
 
 use crate::{
-    cache::{CacheKey, TranspositionTable},
+    cache::{CacheKey, TranspositionTable, ZobristHash},
     error::{RivalError, RivalResult},
-    search::Strategy,
+    limits::SearchLimits,
+    report::{RankedMove, SearchReport},
+    search::{Interrupt, StopHandle, Strategy},
     Moves, Play, SearchResult,
 };
 
+/// Number of moves a game is assumed to last from now on, when the clock does
+/// not say.
+const MOVES_TO_GO: u32 = 30;
+
+/// Depth up to which [`Rival::evaluate_moves`] searches when no limit is set.
+const EVALUATE_DEPTH: u8 = 8;
+
+/// Part of the remaining time a single move never uses, as interrupting a
+/// search takes some time.
+const RESERVE: u32 = 10;
+
+/// How many times its share of the time a move may take before its search is
+/// interrupted.
+const HARD_FACTOR: u32 = 2;
+
+/// Divides the time on a player's clock over their moves, as in games where
+/// each player has a fixed amount of time for the whole game, possibly with
+/// some time added after every move.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TimeManager {
+    remaining: Duration,
+    increment: Duration,
+    moves_to_go: Option<u32>,
+}
+
+impl TimeManager {
+    /// Creates a time manager for a player with the given time left on their
+    /// clock.
+    pub fn new(remaining: Duration) -> Self {
+        TimeManager {
+            remaining,
+            increment: Duration::ZERO,
+            moves_to_go: None,
+        }
+    }
+
+    /// Sets the time added to the clock after every move.
+    pub fn with_increment(mut self, increment: Duration) -> Self {
+        self.increment = increment;
+        self
+    }
+
+    /// Sets the number of moves to make before the clock is reset. If unknown,
+    /// the game is assumed to last another 30 moves.
+    pub fn with_moves_to_go(mut self, moves_to_go: u32) -> Self {
+        self.moves_to_go = Some(moves_to_go);
+        self
+    }
+
+    /// Allocates time for the next move. Each move gets an even share of the
+    /// remaining time plus the increment. As a deeper search usually takes
+    /// longer than all searches before it together, no deeper search is
+    /// started once half of the share is spent, or all of it when the search
+    /// is unsettled. The search is interrupted at twice the share, and a small
+    /// part of the remaining time is always kept in reserve.
+    pub fn limits(&self) -> SearchLimits {
+        let moves_to_go = self.moves_to_go.unwrap_or(MOVES_TO_GO).max(1);
+        let available = self.remaining - self.remaining / RESERVE;
+        let share = (self.remaining / moves_to_go + self.increment).min(available);
+
+        SearchLimits::new()
+            .with_soft_time(share / 2)
+            .with_extended_soft_time(share)
+            .with_hard_time((share * HARD_FACTOR).min(available))
+    }
+}
+
+/// Minimum time between two reports on the progress of a running search.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Function that receives reports, see [`Rival::with_observer`].
+type Notify<M, V> = Box<dyn FnMut(&SearchReport<M, V>) + Send>;
+
+/// Receives the reports of a [`Rival`], see [`Rival::with_observer`]. Shared
+/// with the running search, so that it can report progress.
+struct Observer<M, V> {
+    notify: Notify<M, V>,
+    /// The report of the last search that completed.
+    latest: Option<SearchReport<M, V>>,
+    /// The depth of the search that is running.
+    searching: u8,
+    notified: Instant,
+}
+
+impl<M, V> Observer<M, V> {
+    fn completed(&mut self, report: SearchReport<M, V>) {
+        (self.notify)(&report);
+        self.latest = Some(report);
+        self.notified = Instant::now();
+    }
+
+    /// Reports the latest completed search again, with the depth being
+    /// searched and the work done since, unless that has been done recently.
+    fn progress(&mut self, nodes: u64, elapsed: Duration) {
+        if self.notified.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+
+        if let Some(latest) = &mut self.latest {
+            latest.searching_depth = Some(self.searching);
+            latest.nodes = nodes;
+            latest.elapsed = elapsed;
+            (self.notify)(latest);
+            self.notified = Instant::now();
+        }
+    }
+}
+
+impl<M, V> Debug for Observer<M, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Observer")
+            .field("notified", &self.notified)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An observer shared with the running search.
+type SharedObserver<M, V> = Arc<Mutex<Observer<M, V>>>;
+
+/// Locks the observer, even if it panicked before.
+fn lock<M, V>(observer: &Mutex<Observer<M, V>>) -> MutexGuard<'_, Observer<M, V>> {
+    observer.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Where a search started, to report the work done since.
+struct Start {
+    time: Instant,
+    nodes: u64,
+    probes: u64,
+    hits: u64,
+}
+
+/// The value of the node counter at which searches of the given depth are
+/// interrupted, if any. The first search, of depth one, is never interrupted.
+fn node_limit(limits: &SearchLimits, start: &Start, depth: u8) -> Option<u64> {
+    if depth == 1 {
+        None
+    } else {
+        limits.nodes.map(|nodes| start.nodes + nodes)
+    }
+}
+
+/// What the searches of [`Rival::deepen`] found.
+struct Deepened<M, V> {
+    best: M,
+    score: Option<V>,
+    win_in: Option<i16>,
+    /// The depth of the last search that completed.
+    depth: u8,
+}
+
 #[derive(Debug, Default)]
 pub struct Rival<G: Moves + CacheKey, S: Strategy<G, N, CAP>, const N: usize, const CAP: usize>
 where
     G::Key: Debug,
 {
-    phantom: PhantomData<[(G, S); N]>,
+    phantom: PhantomData<[G; N]>,
+    strategy: S,
     cache: TranspositionTable<G, SearchResult<S::Value, G::Move>, CAP>,
+    stop: StopHandle,
+    observer: Option<SharedObserver<G::Move, S::Score>>,
 }
 
 impl<G: Moves + CacheKey, S: Strategy<G, N, CAP>, const N: usize, const CAP: usize>
@@ -25,31 +182,335 @@ impl<G: Moves + CacheKey, S: Strategy<G, N, CAP>, const N: usize, const CAP: usi
 where
     G::Key: Debug,
 {
-    pub fn new() -> Self {
+    pub fn new() -> Self
+    where
+        S: Default,
+    {
+        Rival::with_strategy(S::default())
+    }
+
+    pub fn with_strategy(strategy: S) -> Self {
         Rival {
             phantom: PhantomData,
+            strategy,
             cache: TranspositionTable::new(),
+            stop: StopHandle::new(),
+            observer: None,
         }
     }
 
+    /// Cancels searches whenever the given handle is stopped, making them
+    /// return [`RivalError::Cancelled`].
+    pub fn with_stop_handle(mut self, stop: StopHandle) -> Self {
+        self.stop = stop;
+        self
+    }
+
+    /// Calls the observer whenever [`search`], [`analyze`] or
+    /// [`get_best_within`] complete a search of some depth, for example to show what the computer is thinking. While
+    /// a deeper search is running, the observer is called again every 100
+    /// milliseconds or so with the depth being searched, the number of states
+    /// visited and the time spent so far.
+    ///
+    /// [`search`]: Self::search
+    /// [`analyze`]: Self::analyze
+    /// [`get_best_within`]: Self::get_best_within
+    pub fn with_observer(
+        mut self,
+        observer: impl FnMut(&SearchReport<G::Move, S::Score>) + Send + 'static,
+    ) -> Self {
+        self.observer = Some(Arc::new(Mutex::new(Observer {
+            notify: Box::new(observer),
+            latest: None,
+            searching: 0,
+            notified: Instant::now(),
+        })));
+        self
+    }
+
     pub fn get_best(&mut self, game: &mut G, depth: u8) -> RivalResult<G::Move> {
-        S::search(game, depth, &mut self.cache)
-            .best
-            .ok_or(RivalError::NoMove)
+        self.strategy.new_search();
+        let interrupt = Interrupt::new().with_stop(self.stop.clone());
+        let result = self
+            .strategy
+            .search(game, depth, &interrupt, &mut self.cache);
+
+        if self.stop.is_stopped() {
+            return Err(RivalError::Cancelled);
+        }
+
+        result.best.ok_or(RivalError::NoMove)
+    }
+
+    /// The total number of states the strategy has visited over all searches.
+    pub fn nodes(&self) -> u64 {
+        self.strategy.nodes()
+    }
+}
+
+impl<
+        G: Moves + Play + ZobristHash + CacheKey,
+        S: Strategy<G, N, CAP>,
+        const N: usize,
+        const CAP: usize,
+    > Rival<G, S, N, CAP>
+where
+    G::Key: Debug,
+    G::Move: Clone + PartialEq + Send + 'static,
+{
+    /// Searches deeper and deeper until one of the limits is reached,
+    /// interrupting the last search if necessary. The first search, of depth
+    /// one, always completes unless cancelled, so that there is a move to play.
+    pub fn search(&mut self, game: &mut G, limits: SearchLimits) -> RivalResult<G::Move> {
+        self.analyze(game, limits).map(|report| report.best)
     }
 
+    /// Searches deeper and deeper until the timeout expires, interrupting the
+    /// last search if necessary, like [`search`] with only a hard time limit.
+    ///
+    /// [`search`]: Self::search
     pub fn get_best_within(&mut self, game: &mut G, timeout: Duration) -> RivalResult<G::Move> {
-        let start_time = Instant::now();
-        let mut depth = 1;
-        let mut best = None;
+        self.search(game, SearchLimits::new().with_hard_time(timeout))
+    }
+
+    pub fn play_within(&mut self, game: &mut G, timeout: Duration) -> RivalResult<()> {
+        let best = self.get_best_within(game, timeout)?;
+        game.play(&best);
+
+        Ok(())
+    }
+
+    /// Searches like [`search`], but reports more than just the best move, to
+    /// show why it is the best move.
+    ///
+    /// [`search`]: Self::search
+    pub fn analyze(
+        &mut self,
+        game: &mut G,
+        limits: SearchLimits,
+    ) -> RivalResult<SearchReport<G::Move, S::Score>> {
+        let start = self.start();
+        let mut interrupt = self.interrupt(&limits, &start);
+        if let Some(observer) = &self.observer {
+            lock(observer).latest = None;
 
-        while best.is_none() || start_time.elapsed() < timeout {
-            let result = S::search(game, depth, &mut self.cache);
-            best = Some(result.best.ok_or(RivalError::NoMove)?);
-            depth += 1;
+            let observer = observer.clone();
+            let (start_time, start_nodes) = (start.time, start.nodes);
+            interrupt = interrupt.with_progress(move |nodes| {
+                lock(&observer).progress(nodes - start_nodes, start_time.elapsed());
+            });
         }
 
-        Ok(best.unwrap())
+        let deepened = self.deepen(
+            game,
+            &limits,
+            &start,
+            &interrupt,
+            |rival, depth| {
+                if let Some(observer) = &rival.observer {
+                    lock(observer).searching = depth;
+                }
+            },
+            |rival, game, completed| {
+                if let Some(observer) = &rival.observer {
+                    let report = rival.report(game, &start, completed);
+                    lock(observer).completed(report);
+                }
+            },
+        )?;
+
+        Ok(self.report(game, &start, &deepened))
+    }
+
+    /// Ranks the best moves up to the given number, each with its score for
+    /// the player to move and the principal variation that follows it. Every
+    /// move after the first is found by searching again while ignoring the
+    /// moves found before, so each additional move takes about as long as the
+    /// first.
+    ///
+    /// Fewer moves are returned if the game does not have enough moves, or if
+    /// the strategy cannot ignore moves or express its values as a single
+    /// score, as is the case for [`Mcts`].
+    ///
+    /// [`Mcts`]: crate::Mcts
+    pub fn get_best_moves(
+        &mut self,
+        game: &mut G,
+        depth: u8,
+        count: usize,
+    ) -> RivalResult<Vec<RankedMove<G::Move, S::Score>>> {
+        if game.moves().next().is_none() {
+            return Err(RivalError::NoMove);
+        }
+
+        self.strategy.new_search();
+        let interrupt = Interrupt::new().with_stop(self.stop.clone());
+
+        self.rank(game, depth, count, &interrupt, None)
+    }
+
+    /// Scores every move of the game, in the order of [`Moves::moves`], from
+    /// the perspective of the player to move. Like [`search`], this searches
+    /// deeper and deeper until one of the limits is reached, but only the
+    /// scores of the last depth at which every move was searched in full are
+    /// returned. These are exact values for that depth, found by searching
+    /// every move with the full window if the strategy supports
+    /// [`Strategy::score_moves`], or otherwise by searching the game once for
+    /// every move, each time ignoring the moves scored before.
+    ///
+    /// As scoring every move takes much longer than finding the best one,
+    /// this stops at depth 8 if none of the limits is set.
+    ///
+    /// As with [`get_best_moves`], moves are left out if the strategy cannot
+    /// score them.
+    ///
+    /// [`search`]: Self::search
+    /// [`get_best_moves`]: Self::get_best_moves
+    pub fn evaluate_moves(
+        &mut self,
+        game: &mut G,
+        limits: SearchLimits,
+    ) -> RivalResult<Vec<(G::Move, S::Score)>> {
+        let moves: Vec<_> = game.moves().collect();
+        if moves.is_empty() {
+            return Err(RivalError::NoMove);
+        }
+
+        let limits = if limits.is_unlimited() {
+            limits.with_depth(EVALUATE_DEPTH)
+        } else {
+            limits
+        };
+        let start = self.start();
+        let interrupt = self.interrupt(&limits, &start);
+        let mut scored = Vec::new();
+
+        for depth in 1..=limits.depth {
+            let depth_interrupt =
+                match self.depth_interrupt(&limits, &start, &interrupt, depth, false) {
+                    Some(depth_interrupt) => depth_interrupt,
+                    None => break,
+                };
+            let node_limit = node_limit(&limits, &start, depth);
+            let depth_interrupt = self.limit_nodes(&depth_interrupt, node_limit);
+            let depth_scored =
+                match self
+                    .strategy
+                    .score_moves(game, depth, &depth_interrupt, &mut self.cache)
+                {
+                    Some(depth_scored) => depth_scored,
+                    None => self
+                        .rank(game, depth, moves.len(), &depth_interrupt, node_limit)?
+                        .into_iter()
+                        .map(|(m, score, _)| (m, score))
+                        .collect(),
+                };
+
+            if self.stop.is_stopped() {
+                return Err(RivalError::Cancelled);
+            }
+
+            if self.strategy.interrupted() {
+                break;
+            }
+
+            scored = depth_scored;
+        }
+
+        Ok(moves
+            .into_iter()
+            .filter_map(|m| {
+                let score = scored
+                    .iter()
+                    .find(|(scored, _)| *scored == m)
+                    .map(|(_, score)| *score)?;
+
+                Some((m, score))
+            })
+            .collect())
+    }
+
+    /// Searches the game at the given depth again and again, each time ignoring
+    /// the best moves found before, until enough moves are ranked. Stops early
+    /// if a search is interrupted, or once the node counter reaches the limit.
+    fn rank(
+        &mut self,
+        game: &mut G,
+        depth: u8,
+        count: usize,
+        interrupt: &Interrupt,
+        node_limit: Option<u64>,
+    ) -> RivalResult<Vec<RankedMove<G::Move, S::Score>>> {
+        let mut excluded = Vec::new();
+        let mut ranked = Vec::new();
+
+        while ranked.len() < count {
+            let interrupt = self.limit_nodes(interrupt, node_limit);
+            let result =
+                self.strategy
+                    .search_excluding(game, depth, &interrupt, &mut self.cache, &excluded);
+
+            if self.stop.is_stopped() {
+                return Err(RivalError::Cancelled);
+            }
+
+            if self.strategy.interrupted() {
+                break;
+            }
+
+            let (best, score) = match (result.best, self.strategy.score(game, &result.value)) {
+                (Some(best), Some(score)) => (best, score),
+                _ => break,
+            };
+
+            let principal_variation = self.principal_variation(game, &best, depth);
+            excluded.push(best.clone());
+            ranked.push((best, score, principal_variation));
+        }
+
+        Ok(ranked)
+    }
+
+    fn report(
+        &self,
+        game: &mut G,
+        start: &Start,
+        deepened: &Deepened<G::Move, S::Score>,
+    ) -> SearchReport<G::Move, S::Score> {
+        SearchReport {
+            best: deepened.best.clone(),
+            score: deepened.score,
+            win_in: deepened.win_in,
+            depth: deepened.depth,
+            searching_depth: None,
+            principal_variation: self.principal_variation(game, &deepened.best, deepened.depth),
+            nodes: self.nodes() - start.nodes,
+            elapsed: start.time.elapsed(),
+            cache_probes: self.cache.probes() - start.probes,
+            cache_hits: self.cache.hits() - start.hits,
+        }
+    }
+
+    /// Follows the best moves stored in the transposition table, starting with
+    /// the given move, for at most the given number of moves.
+    fn principal_variation(&self, game: &mut G, best: &G::Move, depth: u8) -> Vec<G::Move> {
+        let mut moves = vec![best.clone()];
+        let mut remembers = Vec::new();
+
+        while moves.len() < usize::from(depth) {
+            remembers.push(game.play(&moves[moves.len() - 1]));
+
+            match self.cache.peek(game).and_then(|result| result.best.clone()) {
+                Some(m) => moves.push(m),
+                None => break,
+            }
+        }
+
+        while let Some(remember) = remembers.pop() {
+            game.unplay(remember);
+        }
+
+        moves
     }
 }
 
@@ -57,16 +518,156 @@ impl<G: Moves + Play + CacheKey, S: Strategy<G, N, CAP>, const N: usize, const C
     Rival<G, S, N, CAP>
 where
     G::Key: Debug,
+    G::Move: PartialEq,
 {
-    pub fn play(&mut self, game: &mut G, depth: u8) -> RivalResult<()> {
-        let best = self.get_best(game, depth)?;
-        game.play(&best);
+    /// Searches deeper and deeper until one of the limits is reached, telling
+    /// `searching` about every depth it starts to search and `completed` about
+    /// every search that completes.
+    fn deepen(
+        &mut self,
+        game: &mut G,
+        limits: &SearchLimits,
+        start: &Start,
+        interrupt: &Interrupt,
+        mut searching: impl FnMut(&Self, u8),
+        mut completed: impl FnMut(&Self, &mut G, &Deepened<G::Move, S::Score>),
+    ) -> RivalResult<Deepened<G::Move, S::Score>> {
+        let mut best = None;
+        let mut score = None;
+        let mut win_in = None;
+        let mut depth_completed = 0;
+        let mut unsettled = false;
 
-        Ok(())
+        for depth in 1..=limits.depth {
+            let depth_interrupt =
+                match self.depth_interrupt(limits, start, interrupt, depth, unsettled) {
+                    Some(depth_interrupt) => depth_interrupt,
+                    None => break,
+                };
+            let depth_interrupt =
+                self.limit_nodes(&depth_interrupt, node_limit(limits, start, depth));
+
+            searching(self, depth);
+            let result = self
+                .strategy
+                .search(game, depth, &depth_interrupt, &mut self.cache);
+
+            if self.stop.is_stopped() {
+                return Err(RivalError::Cancelled);
+            }
+
+            match result.best {
+                Some(m) if self.strategy.interrupted() => best = Some(m),
+                Some(m) => {
+                    // A different best move or a lower score means the previous search missed
+                    // something, and so might this one
+                    let new_score = self.strategy.score(game, &result.value);
+                    let score_dropped =
+                        matches!((score, new_score), (Some(old), Some(new)) if new < old);
+                    unsettled = best.as_ref().is_some_and(|best| *best != m) || score_dropped;
+                    score = new_score;
+                    win_in = self.strategy.win_in(game, &result.value);
+                    depth_completed = depth;
+
+                    let deepened = Deepened {
+                        best: m,
+                        score,
+                        win_in,
+                        depth,
+                    };
+                    completed(self, game, &deepened);
+                    best = Some(deepened.best);
+
+                    if limits.stop_on_forced_win && self.strategy.is_forced_win(game, &result.value)
+                    {
+                        break;
+                    }
+                }
+                None if depth == 1 => return Err(RivalError::NoMove),
+                None => {}
+            }
+        }
+
+        Ok(Deepened {
+            best: best.ok_or(RivalError::NoMove)?,
+            score,
+            win_in,
+            depth: depth_completed,
+        })
     }
 
-    pub fn play_within(&mut self, game: &mut G, timeout: Duration) -> RivalResult<()> {
-        let best = self.get_best_within(game, timeout)?;
+    /// Prepares the strategy for a new search, and notes where it starts.
+    fn start(&mut self) -> Start {
+        self.strategy.new_search();
+
+        Start {
+            time: Instant::now(),
+            nodes: self.nodes(),
+            probes: self.cache.probes(),
+            hits: self.cache.hits(),
+        }
+    }
+
+    /// Interrupts searches when they are cancelled, or at the hard time.
+    fn interrupt(&self, limits: &SearchLimits, start: &Start) -> Interrupt {
+        let interrupt = Interrupt::new().with_stop(self.stop.clone());
+
+        match limits.hard_time {
+            Some(hard_time) => interrupt.with_deadline(start.time + hard_time),
+            None => interrupt,
+        }
+    }
+
+    /// Decides whether to search the given depth, returning the interrupt for
+    /// that search if so. The first search, of depth one, is always started,
+    /// and can only be cancelled.
+    fn depth_interrupt(
+        &self,
+        limits: &SearchLimits,
+        start: &Start,
+        interrupt: &Interrupt,
+        depth: u8,
+        unsettled: bool,
+    ) -> Option<Interrupt> {
+        if depth == 1 {
+            return Some(Interrupt::new().with_stop(self.stop.clone()));
+        }
+
+        let soft_time = match limits.extended_soft_time {
+            Some(extended_soft_time) if unsettled => limits
+                .soft_time
+                .map(|soft_time| soft_time.max(extended_soft_time)),
+            _ => limits.soft_time,
+        };
+        let soft_time_passed = soft_time.is_some_and(|soft_time| start.time.elapsed() >= soft_time);
+        let nodes_used =
+            node_limit(limits, start, depth).is_some_and(|limit| self.nodes() >= limit);
+
+        if interrupt.is_due() || soft_time_passed || nodes_used {
+            None
+        } else {
+            Some(interrupt.clone())
+        }
+    }
+
+    /// Interrupts the search once the node counter reaches the limit.
+    fn limit_nodes(&self, interrupt: &Interrupt, node_limit: Option<u64>) -> Interrupt {
+        match node_limit {
+            Some(limit) => interrupt
+                .clone()
+                .with_nodes(limit.saturating_sub(self.nodes())),
+            None => interrupt.clone(),
+        }
+    }
+}
+
+impl<G: Moves + Play + CacheKey, S: Strategy<G, N, CAP>, const N: usize, const CAP: usize>
+    Rival<G, S, N, CAP>
+where
+    G::Key: Debug,
+{
+    pub fn play(&mut self, game: &mut G, depth: u8) -> RivalResult<()> {
+        let best = self.get_best(game, depth)?;
         game.play(&best);
 
         Ok(())
@@ -0,0 +1,162 @@
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    ops::Neg,
+};
+
+use crate::search::HasMin;
+
+/// The most moves ahead a forced win can be told apart from a faster one.
+pub const MAX_PLY: i16 = 1000;
+
+/// A type of number that games can evaluate their states with, such as
+/// [`Value`]. Implemented for `i16`, `i32`, `i64` and [`OrderedFloat`].
+///
+/// [`Value`]: crate::Value
+pub trait Score: HasMin + Copy + Ord + Debug + Neg<Output = Self> + Send + 'static {
+    /// The highest possible score, which must be the negation of
+    /// [`HasMin::MIN`].
+    const MAX: Self;
+
+    /// The score of having won the game, as found by strategies built on
+    /// alpha-beta search, such as [`Negamax`]. These strategies score a state
+    /// the game reports as won, through [`EvaluateZeroSum::is_win`], as this
+    /// score less the number of moves it takes to get there, so that they
+    /// prefer faster wins and slower losses. A forced loss scores the negation.
+    ///
+    /// Evaluations should stay well below this score less [`MAX_PLY`], so that
+    /// they cannot be mistaken for a forced win.
+    ///
+    /// [`Negamax`]: crate::Negamax
+    /// [`EvaluateZeroSum::is_win`]: crate::EvaluateZeroSum::is_win
+    const FORCED_WIN: Self;
+
+    /// The lowest score above this one, to test whether the value of a state
+    /// is above a score by searching it with a window between the two. The
+    /// highest score has no successor, and returns itself.
+    fn successor(self) -> Self;
+
+    /// The score of a forced win the given number of moves ahead, counting at
+    /// most [`MAX_PLY`] moves.
+    fn forced_win(moves: usize) -> Self;
+
+    /// The number of moves until the forced win this score stands for, if
+    /// any, or the negative number of moves until the forced loss.
+    fn win_in(self) -> Option<i16>;
+}
+
+macro_rules! impl_score {
+    ($($int:ty),*) => {
+        $(
+            impl HasMin for $int {
+                // Plus one to prevent overflow when negating
+                const MIN: Self = <$int>::MIN + 1;
+            }
+
+            impl Score for $int {
+                const MAX: Self = <$int>::MAX;
+                const FORCED_WIN: Self = <$int>::MAX - 1;
+
+                fn successor(self) -> Self {
+                    self.saturating_add(1)
+                }
+
+                fn forced_win(moves: usize) -> Self {
+                    Self::FORCED_WIN - moves.min(MAX_PLY as usize) as $int
+                }
+
+                fn win_in(self) -> Option<i16> {
+                    let max_ply = <$int>::from(MAX_PLY);
+
+                    if (Self::FORCED_WIN - max_ply..=Self::FORCED_WIN).contains(&self) {
+                        Some((Self::FORCED_WIN - self) as i16)
+                    } else if (-Self::FORCED_WIN..=max_ply - Self::FORCED_WIN).contains(&self) {
+                        Some(-((Self::FORCED_WIN + self) as i16))
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_score!(i16, i32, i64);
+
+/// A floating-point score for games whose evaluations are not naturally whole
+/// numbers. Unlike `f64` itself, scores are totally ordered, as by
+/// [`f64::total_cmp`]: `NaN` is above every other number, unless its sign bit
+/// is set, in which case it is below every other number, and `-0.0` is below
+/// `0.0`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct OrderedFloat(pub f64);
+
+impl PartialEq for OrderedFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedFloat {}
+
+impl PartialOrd for OrderedFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Hash for OrderedFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl Neg for OrderedFloat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        OrderedFloat(-self.0)
+    }
+}
+
+impl From<f64> for OrderedFloat {
+    fn from(value: f64) -> Self {
+        OrderedFloat(value)
+    }
+}
+
+impl HasMin for OrderedFloat {
+    const MIN: Self = OrderedFloat(f64::NEG_INFINITY);
+}
+
+impl Score for OrderedFloat {
+    const MAX: Self = OrderedFloat(f64::INFINITY);
+    // Whole numbers up to here are exact, so that every move counts
+    const FORCED_WIN: Self = OrderedFloat(4_503_599_627_370_496.0);
+
+    fn successor(self) -> Self {
+        OrderedFloat(self.0.next_up())
+    }
+
+    fn forced_win(moves: usize) -> Self {
+        OrderedFloat(Self::FORCED_WIN.0 - moves.min(MAX_PLY as usize) as f64)
+    }
+
+    fn win_in(self) -> Option<i16> {
+        let max_ply = f64::from(MAX_PLY);
+        let moves = Self::FORCED_WIN.0 - self.0.abs();
+
+        if (0.0..=max_ply).contains(&moves) && moves.fract() == 0.0 {
+            Some(if self.0 > 0.0 { moves } else { -moves } as i16)
+        } else {
+            None
+        }
+    }
+}
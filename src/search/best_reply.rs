@@ -0,0 +1,366 @@
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{
+        interrupt::Counter, noisy_moves, ordered_moves, Bound, HasMin, Interrupt, SearchResult,
+        Strategy, QUIESCENCE_DEPTH,
+    },
+    Evaluate, Moves, Play, Score,
+};
+
+/// Search strategy for games with any number of players, also known as
+/// Best-Reply Search. Between two turns of the player to move, only the single
+/// strongest reply of any one opponent is considered, treating all opponents'
+/// turns as a single layer of the search tree. This allows the search to look
+/// ahead further than [`Paranoid`] or [`MaxN`] in games with many players.
+///
+/// Because the game decides whose turn it is, the opponents that do not reply
+/// play the move with the highest [`Moves::priority`] instead of passing.
+///
+/// At the maximum depth, states that are not [`Evaluate::quiet`] are searched
+/// a few moves further, 8 at most by default, but only along their
+/// [`Moves::noisy_moves`]. There, every player may reply, and the player to
+/// move may also settle for the evaluation of the state.
+///
+/// [`Paranoid`]: crate::Paranoid
+/// [`MaxN`]: crate::MaxN
+#[derive(Clone, Debug)]
+pub struct BestReply {
+    quiescence_depth: u8,
+    counter: Counter,
+}
+
+impl BestReply {
+    pub fn new() -> Self {
+        BestReply {
+            quiescence_depth: QUIESCENCE_DEPTH,
+            counter: Counter::default(),
+        }
+    }
+
+    /// Sets how many moves past the maximum depth the quiescence search looks
+    /// at most. Defaults to 8.
+    pub fn with_quiescence_depth(mut self, quiescence_depth: u8) -> Self {
+        self.quiescence_depth = quiescence_depth;
+        self
+    }
+
+    /// Searches a state in which the root player is to move, returning the
+    /// root player's score.
+    fn search_root<
+        S: Evaluate<N> + Play + Moves + ZobristHash + CacheKey,
+        const N: usize,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        root: usize,
+        depth: u8,
+        (mut alpha, mut beta): (S::Score, S::Score),
+        excluded: &[S::Move],
+        cache: &mut TranspositionTable<S, SearchResult<S::Score, S::Move>, CAP>,
+    ) -> SearchResult<S::Score, S::Move>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if self.counter.visit() {
+            return SearchResult::MIN;
+        }
+
+        let cached = if excluded.is_empty() {
+            cache.get(state).copied()
+        } else {
+            None
+        };
+
+        if let Some(result) = cached.filter(|result| result.depth >= depth) {
+            match result.bound {
+                Bound::Exact => return result,
+                Bound::Lower if result.value >= beta => return result,
+                Bound::Upper if result.value <= alpha => return result,
+                Bound::Lower => alpha = alpha.max(result.value),
+                Bound::Upper => beta = beta.min(result.value),
+            }
+        }
+
+        let (original_alpha, original_beta) = (alpha, beta);
+
+        let best = if state.moves().next().is_none() {
+            SearchResult {
+                depth: u8::MAX,
+                value: state.evaluate()[root],
+                bound: Bound::Exact,
+                best: None,
+            }
+        } else if depth == 0 {
+            let value = self.quiesce(state, root, self.quiescence_depth, (alpha, beta));
+            if self.counter.interrupted() {
+                return SearchResult::MIN;
+            }
+
+            SearchResult {
+                depth: 0,
+                value,
+                bound: Bound::of(value, original_alpha, original_beta),
+                best: None,
+            }
+        } else {
+            let mut best = SearchResult::MIN;
+
+            let first = cached.and_then(|result| result.best);
+            let state_ptr: *mut S = state;
+            let moves = ordered_moves(state, first);
+            for m in moves.into_iter().filter(|m| !excluded.contains(m)) {
+                // Safety: as long as unplay properly restores any existing references that play
+                // destroys, this should be safe, right?
+                let next_state = unsafe { &mut *state_ptr };
+                let remember = next_state.play(&m);
+                let current = if next_state.turn() == root {
+                    self.search_root(next_state, root, depth - 1, (alpha, beta), &[], cache)
+                } else {
+                    self.search_opponents(next_state, root, depth - 1, false, (alpha, beta), cache)
+                };
+                next_state.unplay(remember);
+
+                if self.counter.interrupted() {
+                    break;
+                }
+
+                if current.value > best.value {
+                    alpha = alpha.max(current.value);
+                    best = SearchResult {
+                        depth: current.depth.saturating_add(1),
+                        value: current.value,
+                        bound: Bound::Exact,
+                        best: Some(m),
+                    };
+                }
+
+                if alpha >= beta {
+                    break;
+                }
+            }
+
+            if self.counter.interrupted() {
+                best.interrupted(first);
+
+                return best;
+            }
+
+            best.bound = Bound::of(best.value, original_alpha, original_beta);
+
+            // When every move failed low, none of them is known to beat the previous best move
+            if best.bound == Bound::Upper {
+                best.best = cached.and_then(|result| result.best);
+            }
+
+            best
+        };
+
+        if excluded.is_empty() {
+            cache.insert(state, best);
+        }
+
+        best
+    }
+
+    /// Searches a state in which an opponent of the root player is to move,
+    /// returning the root player's score. Once one of the opponents has
+    /// `replied`, the remaining opponents only consider their first move.
+    fn search_opponents<
+        S: Evaluate<N> + Play + Moves + ZobristHash + CacheKey,
+        const N: usize,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        root: usize,
+        depth: u8,
+        replied: bool,
+        (alpha, mut beta): (S::Score, S::Score),
+        cache: &mut TranspositionTable<S, SearchResult<S::Score, S::Move>, CAP>,
+    ) -> SearchResult<S::Score, S::Move>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if self.counter.visit() {
+            return SearchResult::MIN;
+        }
+
+        if state.moves().next().is_none() {
+            SearchResult {
+                depth: u8::MAX,
+                value: state.evaluate()[root],
+                bound: Bound::Exact,
+                best: None,
+            }
+        } else if depth == 0 {
+            SearchResult {
+                depth: 0,
+                value: self.quiesce(state, root, self.quiescence_depth, (alpha, beta)),
+                bound: Bound::Exact,
+                best: None,
+            }
+        } else {
+            let mut best = SearchResult {
+                depth: 0,
+                value: S::Score::MAX,
+                bound: Bound::Exact,
+                best: None,
+            };
+
+            let state_ptr: *mut S = state;
+            let moves = ordered_moves(state, None);
+            let moves = moves.into_iter().take(if replied { 1 } else { usize::MAX });
+            for (i, m) in moves.enumerate() {
+                // Safety: as long as unplay properly restores any existing references that play
+                // destroys, this should be safe, right?
+                let next_state = unsafe { &mut *state_ptr };
+                let remember = next_state.play(&m);
+                // The layer of opponents ends once it is the root player's turn again
+                let current = if next_state.turn() == root {
+                    self.search_root(next_state, root, depth - 1, (alpha, beta), &[], cache)
+                } else {
+                    self.search_opponents(
+                        next_state,
+                        root,
+                        depth,
+                        replied || i > 0,
+                        (alpha, beta),
+                        cache,
+                    )
+                };
+                next_state.unplay(remember);
+
+                if self.counter.interrupted() {
+                    break;
+                }
+
+                if best.best.is_none() || current.value < best.value {
+                    beta = beta.min(current.value);
+                    best = SearchResult {
+                        depth: current.depth,
+                        value: current.value,
+                        bound: Bound::Exact,
+                        best: Some(m),
+                    };
+                }
+
+                if alpha >= beta {
+                    break;
+                }
+            }
+
+            best
+        }
+    }
+
+    /// Evaluates a state for the root player, unless it is not quiet, in which
+    /// case its noisy moves are searched until the states are quiet, or for at
+    /// most the given number of moves. The root player maximizes the score and
+    /// the opponents minimize it, but whoever is to move may also keep the
+    /// evaluation of the state. Returns a fail-soft value like an alpha-beta
+    /// search.
+    fn quiesce<S: Evaluate<N> + Play + Moves, const N: usize>(
+        &mut self,
+        state: &mut S,
+        root: usize,
+        depth: u8,
+        (mut alpha, mut beta): (S::Score, S::Score),
+    ) -> S::Score
+    where
+        S::Move: Copy,
+    {
+        let stand_pat = state.evaluate()[root];
+        if depth == 0 || state.quiet() {
+            return stand_pat;
+        }
+
+        let maximize = state.turn() == root;
+        let mut best = stand_pat;
+
+        for m in noisy_moves(state) {
+            if maximize {
+                alpha = alpha.max(best);
+            } else {
+                beta = beta.min(best);
+            }
+
+            if alpha >= beta || self.counter.visit() {
+                break;
+            }
+
+            let remember = state.play(&m);
+            let current = self.quiesce(state, root, depth - 1, (alpha, beta));
+            state.unplay(remember);
+
+            if self.counter.interrupted() {
+                break;
+            }
+
+            best = if maximize {
+                best.max(current)
+            } else {
+                best.min(current)
+            };
+        }
+
+        best
+    }
+}
+
+impl Default for BestReply {
+    fn default() -> Self {
+        BestReply::new()
+    }
+}
+
+impl<S: Evaluate<N> + Play + Moves + ZobristHash + CacheKey, const N: usize, const CAP: usize>
+    Strategy<S, N, CAP> for BestReply
+where
+    S::Move: Copy + PartialEq,
+{
+    type Value = S::Score;
+    type Score = S::Score;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        let alpha = S::Score::MIN;
+        let beta = S::Score::MAX;
+        let root = state.turn();
+        self.counter.start(interrupt);
+
+        self.search_root(state, root, depth, (alpha, beta), excluded, cache)
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, state: &S, value: &Self::Value) -> bool {
+        state.max_score().is_some_and(|max| *value >= max)
+    }
+
+    fn score(&self, _state: &S, value: &Self::Value) -> Option<Self::Score> {
+        Some(*value)
+    }
+}
@@ -0,0 +1,478 @@
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{
+        interrupt::Counter, noisy_moves, ordered_moves, Bound, Interrupt, SearchResult, Strategy,
+        QUIESCENCE_DEPTH,
+    },
+    ChanceMoves, EvaluateZeroSum, Play, Value,
+};
+
+/// Search strategy for two-player zero-sum games with random events, as
+/// described by [`ChanceMoves`]. The value of a chance node is the average
+/// value of its outcomes, weighted by their probabilities. Random events do not
+/// count towards the search depth.
+///
+/// If the game provides [`EvaluateZeroSum::max_value`], outcomes that cannot
+/// change the result are skipped (Star1). The tighter this bound, the more
+/// outcomes can be skipped.
+///
+/// Optionally, the search first probes a single move after every outcome,
+/// before searching any outcome in full (Star2). This finds cutoffs sooner if
+/// the move with the highest [`Moves::priority`] tends to be a good one, but
+/// costs more than it saves otherwise.
+///
+/// Where the maximum depth is reached in a state that is not
+/// [`EvaluateZeroSum::quiet`], the search goes on along the
+/// [`Moves::noisy_moves`] only, for at most 8 moves by default, and stops at
+/// random events. The player to move may also keep the evaluation of the
+/// state if that is better than any noisy move.
+///
+/// Only games scored in [`Value`] are supported, as the value of a chance node
+/// is a weighted average that is rounded to a whole number.
+///
+/// [`Moves::priority`]: crate::Moves::priority
+/// [`Moves::noisy_moves`]: crate::Moves::noisy_moves
+#[derive(Clone, Debug)]
+pub struct Expectimax {
+    probing: bool,
+    quiescence_depth: u8,
+    counter: Counter,
+}
+
+impl Expectimax {
+    pub fn new() -> Self {
+        Expectimax {
+            probing: false,
+            quiescence_depth: QUIESCENCE_DEPTH,
+            counter: Counter::default(),
+        }
+    }
+
+    /// Sets whether to probe the outcomes of random events before searching
+    /// them. Defaults to `false`.
+    pub fn with_probing(mut self, probing: bool) -> Self {
+        self.probing = probing;
+        self
+    }
+
+    /// Sets how many moves past the maximum depth the quiescence search looks
+    /// at most. Defaults to 8.
+    pub fn with_quiescence_depth(mut self, quiescence_depth: u8) -> Self {
+        self.quiescence_depth = quiescence_depth;
+        self
+    }
+
+    /// Searches a state, returning its value from the perspective of the
+    /// maximizing player.
+    fn search_alpha_beta<
+        S: EvaluateZeroSum<Score = Value> + ChanceMoves + Play + ZobristHash + CacheKey,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        mut alpha: Value,
+        mut beta: Value,
+        excluded: &[S::Move],
+        cache: &mut TranspositionTable<S, SearchResult<Value, S::Move>, CAP>,
+    ) -> SearchResult<Value, S::Move>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if self.counter.visit() {
+            return SearchResult::MIN;
+        }
+
+        let cached = if excluded.is_empty() {
+            cache.get(state).copied()
+        } else {
+            None
+        };
+
+        if let Some(result) = cached.filter(|result| result.depth >= depth) {
+            return result;
+        }
+
+        let (original_alpha, original_beta) = (alpha, beta);
+        let outcomes: Option<Vec<_>> = state.outcomes().map(Iterator::collect);
+
+        let (best, leaf) = match outcomes {
+            Some(outcomes) if !outcomes.is_empty() => (
+                self.search_chance(state, &outcomes, depth, alpha, beta, cache),
+                false,
+            ),
+            Some(_) => (
+                SearchResult {
+                    depth: u8::MAX,
+                    value: state.evaluate(),
+                    bound: Bound::Exact,
+                    best: None,
+                },
+                true,
+            ),
+            None if state.moves().next().is_none() => (
+                SearchResult {
+                    depth: u8::MAX,
+                    value: state.evaluate(),
+                    bound: Bound::Exact,
+                    best: None,
+                },
+                true,
+            ),
+            None if depth == 0 && state.quiet() => (
+                SearchResult {
+                    depth: 0,
+                    value: state.evaluate(),
+                    bound: Bound::Exact,
+                    best: None,
+                },
+                true,
+            ),
+            None if depth == 0 => (
+                SearchResult {
+                    depth: 0,
+                    value: self.quiesce(state, self.quiescence_depth, (alpha, beta)),
+                    bound: Bound::Exact,
+                    best: None,
+                },
+                false,
+            ),
+            None => {
+                let maximize = !state.min_turn();
+                let mut best = SearchResult {
+                    depth: 0,
+                    value: if maximize { Value::MIN + 1 } else { Value::MAX },
+                    bound: Bound::Exact,
+                    best: None,
+                };
+
+                let first = cached.and_then(|result| result.best);
+                let state_ptr: *mut S = state;
+                let moves = ordered_moves(state, first);
+                for m in moves.into_iter().filter(|m| !excluded.contains(m)) {
+                    // Safety: as long as unplay properly restores any existing references that
+                    // play destroys, this should be safe, right?
+                    let next_state = unsafe { &mut *state_ptr };
+                    let remember = next_state.play(&m);
+                    let current =
+                        self.search_alpha_beta(next_state, depth - 1, alpha, beta, &[], cache);
+                    next_state.unplay(remember);
+
+                    if self.counter.interrupted() {
+                        best.interrupted(first);
+                        break;
+                    }
+
+                    let improved = if maximize {
+                        current.value > best.value
+                    } else {
+                        current.value < best.value
+                    };
+
+                    if best.best.is_none() || improved {
+                        best = SearchResult {
+                            depth: current.depth.saturating_add(1),
+                            value: current.value,
+                            bound: Bound::Exact,
+                            best: Some(m),
+                        };
+
+                        if maximize {
+                            alpha = alpha.max(best.value);
+                        } else {
+                            beta = beta.min(best.value);
+                        }
+                    }
+
+                    if alpha >= beta {
+                        break;
+                    }
+                }
+
+                (best, false)
+            }
+        };
+
+        if self.counter.interrupted() {
+            return best;
+        }
+
+        // Values outside the window are only bounds on the actual value
+        if excluded.is_empty()
+            && (leaf || (original_alpha < best.value && best.value < original_beta))
+        {
+            cache.insert(state, best);
+        }
+
+        best
+    }
+
+    /// Searches a chance node with the given outcomes.
+    fn search_chance<
+        S: EvaluateZeroSum<Score = Value> + ChanceMoves + Play + ZobristHash + CacheKey,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        outcomes: &[(S::Move, f64)],
+        depth: u8,
+        alpha: Value,
+        beta: Value,
+        cache: &mut TranspositionTable<S, SearchResult<Value, S::Move>, CAP>,
+    ) -> SearchResult<Value, S::Move>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        let (lower, upper) = match state.max_value() {
+            Some(max) => (f64::from(-max), f64::from(max)),
+            None => (f64::from(Value::MIN + 1), f64::from(Value::MAX)),
+        };
+        let (alpha, beta) = (f64::from(alpha), f64::from(beta));
+
+        let bound = |value: f64| SearchResult {
+            depth,
+            value: value.round() as Value,
+            bound: Bound::Exact,
+            best: None,
+        };
+
+        // Bounds on the value of each outcome
+        let mut lows = vec![lower; outcomes.len()];
+        let mut highs = vec![upper; outcomes.len()];
+
+        if self.probing && depth > 0 {
+            let mut low_sum = 0.0;
+            let mut high_sum = 0.0;
+            let mut remaining = 1.0;
+
+            for (i, (m, probability)) in outcomes.iter().enumerate() {
+                remaining -= probability;
+                let child_alpha = (alpha - high_sum - upper * remaining) / probability;
+                let child_beta = (beta - low_sum - lower * remaining) / probability;
+                let window = (
+                    child_alpha.clamp(lower, upper).floor() as Value,
+                    child_beta.clamp(lower, upper).ceil() as Value,
+                );
+
+                let remember = state.play(m);
+                let maximize = !state.min_turn();
+                let probe = self.probe(state, depth, window, cache);
+                state.unplay(remember);
+
+                // The caller discards the results of interrupted searches
+                if self.counter.interrupted() {
+                    return bound(0.0);
+                }
+
+                match probe {
+                    Some(value) if maximize && value > window.0 => lows[i] = f64::from(value),
+                    Some(value) if !maximize && value < window.1 => highs[i] = f64::from(value),
+                    _ => {}
+                }
+
+                low_sum += probability * lows[i];
+                high_sum += probability * highs[i];
+
+                if low_sum + lower * remaining >= beta {
+                    return bound(low_sum + lower * remaining);
+                }
+                if high_sum + upper * remaining <= alpha {
+                    return bound(high_sum + upper * remaining);
+                }
+            }
+        }
+
+        let mut sum = 0.0;
+        let mut pending_low: f64 = outcomes.iter().zip(&lows).map(|((_, p), l)| p * l).sum();
+        let mut pending_high: f64 = outcomes.iter().zip(&highs).map(|((_, p), h)| p * h).sum();
+        let mut min_depth = u8::MAX;
+
+        for (i, (m, probability)) in outcomes.iter().enumerate() {
+            pending_low -= probability * lows[i];
+            pending_high -= probability * highs[i];
+            let child_alpha = (alpha - sum - pending_high) / probability;
+            let child_beta = (beta - sum - pending_low) / probability;
+
+            let remember = state.play(m);
+            let current = self.search_alpha_beta(
+                state,
+                depth,
+                child_alpha.clamp(lower, upper).floor() as Value,
+                child_beta.clamp(lower, upper).ceil() as Value,
+                &[],
+                cache,
+            );
+            state.unplay(remember);
+
+            // The caller discards the results of interrupted searches
+            if self.counter.interrupted() {
+                return bound(0.0);
+            }
+
+            let value = f64::from(current.value);
+            min_depth = min_depth.min(current.depth);
+
+            if value <= child_alpha {
+                return bound(sum + probability * value + pending_high);
+            }
+            if value >= child_beta {
+                return bound(sum + probability * value + pending_low);
+            }
+
+            sum += probability * value;
+        }
+
+        SearchResult {
+            depth: min_depth,
+            value: sum.round() as Value,
+            bound: Bound::Exact,
+            best: None,
+        }
+    }
+
+    /// Searches only the first move of a state in which a player is to move.
+    /// The result is a lower bound on the value of the state if the maximizing
+    /// player is to move, or an upper bound if the minimizing player is.
+    fn probe<
+        S: EvaluateZeroSum<Score = Value> + ChanceMoves + Play + ZobristHash + CacheKey,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        (alpha, beta): (Value, Value),
+        cache: &mut TranspositionTable<S, SearchResult<Value, S::Move>, CAP>,
+    ) -> Option<Value>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if depth == 0 || state.outcomes().is_some() {
+            return None;
+        }
+
+        let m = *ordered_moves(state, None).first()?;
+        let remember = state.play(&m);
+        let value = self
+            .search_alpha_beta(state, depth - 1, alpha, beta, &[], cache)
+            .value;
+        state.unplay(remember);
+
+        Some(value)
+    }
+
+    /// Evaluates a state, unless it is not quiet, in which case its noisy moves
+    /// are searched until the states are quiet, a random event is about to
+    /// happen, or the given number of moves have been played. The player to
+    /// move may keep the evaluation of the state if that is better for them.
+    /// Returns a fail-soft value like an alpha-beta search.
+    fn quiesce<S: EvaluateZeroSum<Score = Value> + ChanceMoves + Play>(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        (mut alpha, mut beta): (Value, Value),
+    ) -> Value
+    where
+        S::Move: Copy,
+    {
+        let stand_pat = state.evaluate();
+        if depth == 0 || state.quiet() || state.outcomes().is_some() {
+            return stand_pat;
+        }
+
+        let maximize = !state.min_turn();
+        let mut best = stand_pat;
+
+        for m in noisy_moves(state) {
+            if maximize {
+                alpha = alpha.max(best);
+            } else {
+                beta = beta.min(best);
+            }
+
+            if alpha >= beta || self.counter.visit() {
+                break;
+            }
+
+            let remember = state.play(&m);
+            let current = self.quiesce(state, depth - 1, (alpha, beta));
+            state.unplay(remember);
+
+            if self.counter.interrupted() {
+                break;
+            }
+
+            best = if maximize {
+                best.max(current)
+            } else {
+                best.min(current)
+            };
+        }
+
+        best
+    }
+}
+
+impl Default for Expectimax {
+    fn default() -> Self {
+        Expectimax::new()
+    }
+}
+
+impl<
+        S: EvaluateZeroSum<Score = Value> + ChanceMoves + Play + ZobristHash + CacheKey,
+        const CAP: usize,
+    > Strategy<S, 2, CAP> for Expectimax
+where
+    S::Move: Copy + PartialEq,
+{
+    type Value = Value;
+    type Score = Value;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.counter.start(interrupt);
+
+        let alpha = Value::MIN + 1;
+        let beta = Value::MAX;
+        let result = self.search_alpha_beta(state, depth, alpha, beta, excluded, cache);
+
+        // Like Negamax, report the value from the perspective of the player to move
+        if state.min_turn() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, state: &S, value: &Self::Value) -> bool {
+        state.max_value().is_some_and(|max| *value >= max)
+    }
+
+    fn score(&self, _state: &S, value: &Self::Value) -> Option<Value> {
+        Some(*value)
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// Number of killer moves remembered for every ply.
+const KILLERS: usize = 2;
+
+/// Remembers which moves caused cutoffs, so that they can be searched early
+/// in other states as well. Killer moves are the most recent moves that caused
+/// a cutoff at the same distance from the root, while the history table keeps
+/// track of how often and how deep every move caused a cutoff anywhere in the
+/// search.
+///
+/// Moves are identified by their hash, so two moves may occasionally be
+/// confused. This only affects the order in which moves are searched.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Heuristics {
+    killers: Vec<[Option<u64>; KILLERS]>,
+    history: HashMap<u64, u64>,
+}
+
+impl Heuristics {
+    fn key<M: Hash>(m: &M) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        m.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Scores a move at the given ply, higher scores being searched first.
+    /// Killer moves come before any other move, ordered by how recently they
+    /// caused a cutoff, followed by the other moves ordered by their history.
+    pub(crate) fn score<M: Hash>(&self, ply: usize, m: &M) -> (usize, u64) {
+        let key = Heuristics::key(m);
+        let killer = self.killers.get(ply).map_or(0, |killers| {
+            killers
+                .iter()
+                .position(|&killer| killer == Some(key))
+                .map_or(0, |i| KILLERS - i)
+        });
+
+        (killer, self.history.get(&key).copied().unwrap_or(0))
+    }
+
+    /// Records that a move caused a cutoff at the given ply, in a search of
+    /// the given depth.
+    pub(crate) fn cutoff<M: Hash>(&mut self, ply: usize, m: &M, depth: u8) {
+        let key = Heuristics::key(m);
+
+        if self.killers.len() <= ply {
+            self.killers.resize(ply + 1, [None; KILLERS]);
+        }
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(key) {
+            killers.rotate_right(1);
+            killers[0] = Some(key);
+        }
+
+        // Cutoffs close to the root save the most work
+        *self.history.entry(key).or_insert(0) += u64::from(depth) * u64::from(depth);
+    }
+
+    /// Halves the history of every move, so that cutoffs in recent searches
+    /// outweigh those in older ones.
+    pub(crate) fn age(&mut self) {
+        self.history.retain(|_, history| {
+            *history /= 2;
+            *history > 0
+        });
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+/// Number of states visited between two checks of whether a search should be
+/// interrupted, as checking the time is not free.
+const POLL_INTERVAL: u64 = 256;
+
+/// Conditions under which a search stops before reaching its depth. By
+/// default, a search is never interrupted.
+#[derive(Clone, Debug, Default)]
+pub struct Interrupt {
+    deadline: Option<Instant>,
+    stop: Option<StopHandle>,
+    nodes: Option<u64>,
+    progress: Option<Progress>,
+}
+
+impl Interrupt {
+    pub fn new() -> Self {
+        Interrupt {
+            deadline: None,
+            stop: None,
+            nodes: None,
+            progress: None,
+        }
+    }
+
+    /// Interrupts the search once the given moment has passed.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Interrupts the search once the handle is stopped.
+    pub fn with_stop(mut self, stop: StopHandle) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Interrupts the search once it has visited this many states.
+    pub fn with_nodes(mut self, nodes: u64) -> Self {
+        self.nodes = Some(nodes);
+        self
+    }
+
+    /// Calls the function with the total number of states the strategy has
+    /// visited, every time the search checks whether it should stop.
+    pub fn with_progress(mut self, progress: impl Fn(u64) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Progress(Arc::new(progress)));
+        self
+    }
+
+    /// Indicates whether a search should stop right away, regardless of how
+    /// many states it has visited.
+    pub fn is_due(&self) -> bool {
+        self.stop.as_ref().is_some_and(StopHandle::is_stopped)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+#[derive(Clone)]
+struct Progress(Arc<dyn Fn(u64) + Send + Sync>);
+
+impl Debug for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Progress")
+    }
+}
+
+/// Allows another thread to cancel the searches of a [`Rival`], for example
+/// when the game is closed while the computer is thinking. Clones share the
+/// same flag. Once stopped, every search is cancelled until the handle is
+/// reset.
+///
+/// [`Rival`]: crate::Rival
+#[derive(Clone, Debug, Default)]
+pub struct StopHandle {
+    stopped: Arc<AtomicBool>,
+}
+
+impl StopHandle {
+    pub fn new() -> Self {
+        StopHandle::default()
+    }
+
+    /// Cancels running and future searches.
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Allows searches to run again.
+    pub fn reset(&self) {
+        self.stopped.store(false, Ordering::Relaxed);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts the states a strategy visits, and checks every so often whether the
+/// current search should be interrupted.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Counter {
+    nodes: u64,
+    start: u64,
+    interrupt: Interrupt,
+    interrupted: bool,
+}
+
+impl Counter {
+    /// Prepares for a new search, which stops when the interrupt is due.
+    pub(crate) fn start(&mut self, interrupt: &Interrupt) {
+        self.interrupt = interrupt.clone();
+        self.interrupted = false;
+        self.start = self.nodes;
+    }
+
+    /// Counts a visited state, returning whether the search has been
+    /// interrupted.
+    pub(crate) fn visit(&mut self) -> bool {
+        self.nodes += 1;
+
+        // Counting is cheap, and interrupting at the exact same state every time makes searches
+        // reproducible
+        if let Some(nodes) = self.interrupt.nodes {
+            self.interrupted |= self.nodes - self.start > nodes;
+        }
+
+        if !self.interrupted && self.nodes.is_multiple_of(POLL_INTERVAL) {
+            if let Some(progress) = &self.interrupt.progress {
+                (progress.0)(self.nodes);
+            }
+            self.interrupted = self.interrupt.is_due();
+        }
+
+        self.interrupted
+    }
+
+    /// Indicates whether the current search has been interrupted, in which
+    /// case the results of all states still being searched are incomplete.
+    pub(crate) fn interrupted(&self) -> bool {
+        self.interrupted
+    }
+
+    /// The total number of states visited over all searches.
+    pub(crate) fn nodes(&self) -> u64 {
+        self.nodes
+    }
+}
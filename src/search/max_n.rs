@@ -1,70 +1,327 @@
-// use crate::{
-//     cache::{TranspositionTable, ZobristHash},
-//     search::{SearchResult, Strategy},
-//     Evaluate, Moves, Play, Value,
-// };
-//
-// #[derive(Copy, Clone, Debug)]
-// pub struct MaxN;
-//
-// impl<S: Evaluate<N> + Play + Moves + ZobristHash + Eq, const N: usize, const
-// CAP: usize>     Strategy<S, N, CAP> for MaxN
-// where
-//     S::Move: Copy,
-// {
-//     type Value = [Value; N];
-//
-//     // TODO: Implement pruning
-//     fn search(
-//         state: &mut S,
-//         depth: u8,
-//         cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>,
-// CAP>,     ) -> SearchResult<Self::Value, S::Move> {
-//         if let Some(result) = cache.get(state) {
-//             if result.depth >= depth {
-//                 return *result;
-//             }
-//         }
-//
-//         let best = if state.moves().next().is_none() {
-//             SearchResult {
-//                 depth: u8::MAX,
-//                 value: state.evaluate(),
-//                 best: None,
-//             }
-//         } else if depth == 0 && state.quiet() {
-//             SearchResult {
-//                 depth: 0,
-//                 value: state.evaluate(),
-//                 best: None,
-//             }
-//         } else {
-//             let mut best = SearchResult::<Self::Value, S::Move>::MIN;
-//
-//             let turn = state.turn();
-//             let state_ptr: *mut S = state;
-//             for m in state.moves() {
-//                 // Safety: as long as unplay properly restores any existing
-// references that play                 // destroys, this should be safe, right?
-//                 let next_state = unsafe { &mut *state_ptr };
-//                 let remember = next_state.play(&m);
-//                 let current = Self::search(next_state, depth - 1, cache);
-//                 next_state.unplay(remember);
-//
-//                 if current.value[turn] > best.value[turn] {
-//                     best = SearchResult {
-//                         depth: current.depth.saturating_add(1),
-//                         value: current.value,
-//                         best: Some(m),
-//                     };
-//                 }
-//             }
-//
-//             best
-//         };
-//
-//         cache.insert(state, best);
-//
-//         best
-//     }
-// }
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{
+        interrupt::Counter, noisy_moves, ordered_moves, Bound, Interrupt, SearchResult, Strategy,
+        QUIESCENCE_DEPTH,
+    },
+    Evaluate, Moves, Play, Value,
+};
+
+/// Search strategy for games with any number of players. Every player is
+/// assumed to pick the move that maximizes their own score, regardless of how
+/// it affects the other players.
+///
+/// If the game provides [`Evaluate::max_score`] or [`Evaluate::max_sum`],
+/// branches that cannot change the outcome are pruned. Without these bounds,
+/// every move is searched.
+///
+/// States that are not [`Evaluate::quiet`] at the maximum depth are searched
+/// further, following only their [`Moves::noisy_moves`], for at most 8 more
+/// moves by default. In each of these states, the player to move may also
+/// keep its evaluation if none of the noisy moves does better for them.
+///
+/// Only games scored in [`Value`] are supported, as pruning adds up the scores
+/// of different players.
+#[derive(Clone, Debug)]
+pub struct MaxN {
+    quiescence_depth: u8,
+    counter: Counter,
+}
+
+/// What a node knows about the choices of its ancestors.
+#[derive(Copy, Clone, Debug)]
+struct Ancestors {
+    /// The player to move in the parent node.
+    turn: usize,
+    /// The best score the parent's player has found so far.
+    value: Value,
+    /// Whether this node is the last move the parent considers.
+    last: bool,
+    /// The player to move in the grandparent node and the best score they have
+    /// found so far, if they are guaranteed to reject the parent's current
+    /// best.
+    grandparent: Option<(usize, Value)>,
+}
+
+/// How much the value of a search result can be trusted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Accuracy {
+    /// The value is exact.
+    Exact,
+    /// The search was cut short, but the parent will reject the result either
+    /// way.
+    ParentRejects,
+    /// The search was cut short, but the grandparent will reject the parent's
+    /// result either way.
+    GrandparentRejects,
+}
+
+impl MaxN {
+    pub fn new() -> Self {
+        MaxN {
+            quiescence_depth: QUIESCENCE_DEPTH,
+            counter: Counter::default(),
+        }
+    }
+
+    /// Sets how many moves past the maximum depth the quiescence search looks
+    /// at most. Defaults to 8.
+    pub fn with_quiescence_depth(mut self, quiescence_depth: u8) -> Self {
+        self.quiescence_depth = quiescence_depth;
+        self
+    }
+
+    fn search_pruned<
+        S: Evaluate<N, Score = Value> + Play + Moves + ZobristHash + CacheKey,
+        const N: usize,
+        const CAP: usize,
+    >(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        ancestors: Option<Ancestors>,
+        excluded: &[S::Move],
+        cache: &mut TranspositionTable<S, SearchResult<[Value; N], S::Move>, CAP>,
+    ) -> (SearchResult<[Value; N], S::Move>, Accuracy)
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if self.counter.visit() {
+            return (SearchResult::MIN, Accuracy::Exact);
+        }
+
+        let cached = if excluded.is_empty() {
+            cache.get(state).copied()
+        } else {
+            None
+        };
+
+        if let Some(result) = cached.filter(|result| result.depth >= depth) {
+            return (result, Accuracy::Exact);
+        }
+
+        if state.moves().next().is_none() {
+            let best = SearchResult {
+                depth: u8::MAX,
+                value: state.evaluate(),
+                bound: Bound::Exact,
+                best: None,
+            };
+            cache.insert(state, best);
+            return (best, Accuracy::Exact);
+        } else if depth == 0 {
+            let value = self.quiesce(state, self.quiescence_depth);
+            if self.counter.interrupted() {
+                return (SearchResult::MIN, Accuracy::Exact);
+            }
+
+            let best = SearchResult {
+                depth: 0,
+                value,
+                bound: Bound::Exact,
+                best: None,
+            };
+            cache.insert(state, best);
+            return (best, Accuracy::Exact);
+        }
+
+        let turn = state.turn();
+        let max_score = state.max_score().map(i32::from);
+        let max_sum = state.max_sum().map(i32::from);
+
+        let mut best = SearchResult::<[Value; N], S::Move>::MIN;
+        let mut accuracy = Accuracy::Exact;
+
+        let first = cached.and_then(|result| result.best);
+        let state_ptr: *mut S = state;
+        let mut moves = ordered_moves(state, first)
+            .into_iter()
+            .filter(|m| !excluded.contains(m))
+            .peekable();
+        while let Some(m) = moves.next() {
+            let last = moves.peek().is_none();
+            let next_ancestors = Ancestors {
+                turn,
+                value: best.value[turn],
+                last,
+                grandparent: ancestors
+                    .filter(|a| {
+                        best.best.is_some() && a.turn != turn && best.value[a.turn] <= a.value
+                    })
+                    .map(|a| (a.turn, a.value)),
+            };
+
+            // Safety: as long as unplay properly restores any existing references that play
+            // destroys, this should be safe, right?
+            let next_state = unsafe { &mut *state_ptr };
+            let remember = next_state.play(&m);
+            let (current, current_accuracy) =
+                self.search_pruned(next_state, depth - 1, Some(next_ancestors), &[], cache);
+            next_state.unplay(remember);
+
+            if self.counter.interrupted() {
+                break;
+            }
+
+            if current_accuracy == Accuracy::GrandparentRejects {
+                accuracy = Accuracy::ParentRejects;
+            }
+
+            if best.best.is_none() || current.value[turn] > best.value[turn] {
+                best = SearchResult {
+                    depth: current.depth.saturating_add(1),
+                    value: current.value,
+                    bound: Bound::Exact,
+                    best: Some(m),
+                };
+            }
+
+            if last {
+                break;
+            }
+
+            let score = i32::from(best.value[turn]);
+
+            // Immediate pruning: no other move can improve this player's score
+            if max_score.is_some_and(|max| score >= max) {
+                break;
+            }
+
+            if let (Some(a), Some(max_sum)) = (ancestors, max_sum) {
+                // Shallow pruning: the parent's player can get at most what is left over from
+                // this player's score, which is not better than what they already have
+                if a.turn != turn && i32::from(a.value) + score >= max_sum {
+                    accuracy = Accuracy::ParentRejects;
+                    break;
+                }
+
+                // Last-branch pruning: the parent's player and the grandparent's player cannot
+                // both improve on what they already have, so the grandparent will reject any
+                // result the parent settles on with this as its last move
+                if let Some((grandparent_turn, grandparent_value)) = a.grandparent {
+                    if a.last
+                        && a.turn != turn
+                        && grandparent_turn != turn
+                        && i32::from(grandparent_value) + i32::from(a.value) + score >= max_sum
+                    {
+                        if accuracy == Accuracy::Exact {
+                            accuracy = Accuracy::GrandparentRejects;
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+
+        if self.counter.interrupted() {
+            best.interrupted(first);
+
+            return (best, accuracy);
+        }
+
+        if accuracy == Accuracy::Exact && excluded.is_empty() {
+            cache.insert(state, best);
+        }
+
+        (best, accuracy)
+    }
+
+    /// Evaluates a state, unless it is not quiet, in which case its noisy moves
+    /// are searched until the states are quiet, or for at most the given number
+    /// of moves. The player to move picks the noisy move that is best for them,
+    /// or keeps the evaluation of the state if that is better still.
+    fn quiesce<S: Evaluate<N, Score = Value> + Play + Moves, const N: usize>(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+    ) -> [Value; N]
+    where
+        S::Move: Copy,
+    {
+        let stand_pat = state.evaluate();
+        if depth == 0 || state.quiet() {
+            return stand_pat;
+        }
+
+        let turn = state.turn();
+        let mut best = stand_pat;
+
+        for m in noisy_moves(state) {
+            if self.counter.visit() {
+                break;
+            }
+
+            let remember = state.play(&m);
+            let current = self.quiesce(state, depth - 1);
+            state.unplay(remember);
+
+            if self.counter.interrupted() {
+                break;
+            }
+
+            if current[turn] > best[turn] {
+                best = current;
+            }
+        }
+
+        best
+    }
+}
+
+impl Default for MaxN {
+    fn default() -> Self {
+        MaxN::new()
+    }
+}
+
+impl<
+        S: Evaluate<N, Score = Value> + Play + Moves + ZobristHash + CacheKey,
+        const N: usize,
+        const CAP: usize,
+    > Strategy<S, N, CAP> for MaxN
+where
+    S::Move: Copy + PartialEq,
+{
+    type Value = [Value; N];
+    type Score = Value;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.counter.start(interrupt);
+        self.search_pruned(state, depth, None, excluded, cache).0
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, state: &S, value: &Self::Value) -> bool {
+        state
+            .max_score()
+            .is_some_and(|max| value[state.turn()] >= max)
+    }
+
+    fn score(&self, state: &S, value: &Self::Value) -> Option<Value> {
+        Some(value[state.turn()])
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{interrupt::Counter, Bound, HasMin, Interrupt, SearchResult, Strategy},
+    Evaluate, Moves, Play, Score,
+};
+
+/// Search strategy that estimates the strength of moves by playing many random
+/// games, also known as Monte Carlo Tree Search. Promising moves are explored
+/// more often, balancing between moves that won often so far and moves that
+/// have been tried only a few times.
+///
+/// Only the outcome of the game matters: the players with the highest score
+/// according to [`Evaluate::evaluate`] at the end of a playout share the win.
+/// This makes this strategy suitable for games for which no good evaluation
+/// function is known, or where the branching factor is too high for other
+/// strategies to look ahead far enough. As only the order of the scores
+/// matters, games can use any [`Score`].
+///
+/// Each call to [`search`] performs a number of iterations for each unit of
+/// depth. Every iteration adds a state to the search tree, which this strategy
+/// keeps itself rather than in the transposition table, so that no statistics
+/// are lost when entries are overwritten, and repeated calls, such as those
+/// made by [`Rival::get_best_within`], continue where the previous call left
+/// off. States are told apart by their [`ZobristHash`] alone.
+///
+/// [`search`]: Strategy::search
+/// [`Rival::get_best_within`]: crate::Rival::get_best_within
+#[derive(Clone, Debug)]
+pub struct Mcts {
+    iterations: u32,
+    exploration: f64,
+    playout_depth: u16,
+    random: u64,
+    tree: HashMap<usize, Node>,
+    counter: Counter,
+}
+
+impl Mcts {
+    /// Creates a strategy that performs the given number of iterations for each
+    /// unit of search depth.
+    pub fn new(iterations: u32) -> Self {
+        Mcts {
+            iterations,
+            exploration: std::f64::consts::SQRT_2,
+            playout_depth: u16::MAX,
+            random: 0x2545_f491_4f6c_dd1d,
+            tree: HashMap::new(),
+            counter: Counter::default(),
+        }
+    }
+
+    /// Sets how much the search favors moves that have been tried few times
+    /// over moves that won often. Defaults to the square root of two.
+    pub fn with_exploration(mut self, exploration: f64) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    /// Sets the maximum number of moves played in a random playout before the
+    /// state is evaluated as if the game ended. Defaults to [`u16::MAX`].
+    pub fn with_playout_depth(mut self, playout_depth: u16) -> Self {
+        self.playout_depth = playout_depth;
+        self
+    }
+
+    /// Sets the seed of the random number generator used for playouts.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        // Xorshift gets stuck on zero
+        self.random = seed.max(1);
+        self
+    }
+
+    fn random(&mut self) -> u64 {
+        self.random ^= self.random >> 12;
+        self.random ^= self.random << 25;
+        self.random ^= self.random >> 27;
+        self.random.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Selects a move, expands the tree if necessary, performs a random playout
+    /// and updates the statistics of all states along the way. Returns the
+    /// rewards of the playout.
+    fn iterate<S: Evaluate<N> + Play + Moves + ZobristHash, const N: usize>(
+        &mut self,
+        state: &mut S,
+    ) -> [f64; N]
+    where
+        S::Move: Copy,
+    {
+        self.counter.visit();
+
+        let playouts = self.playouts(state);
+
+        let rewards = if state.moves().next().is_none() {
+            rewards(&state.evaluate())
+        } else if let Some(playouts) = playouts {
+            let m = self.select(state, &playouts);
+            let remember = state.play(&m);
+            let rewards = self.iterate(state);
+            state.unplay(remember);
+            rewards
+        } else {
+            self.playout(state)
+        };
+
+        let node = self.tree.entry(state.zobrist_hash()).or_insert(Node {
+            visits: 0,
+            rewards: vec![0.0; N],
+        });
+        node.visits += 1;
+        for (total, reward) in node.rewards.iter_mut().zip(rewards) {
+            *total += reward;
+        }
+
+        rewards
+    }
+
+    /// The statistics of the given state, if it is part of the search tree.
+    fn playouts<S: ZobristHash, const N: usize>(&self, state: &S) -> Option<Playouts<N>> {
+        self.tree.get(&state.zobrist_hash()).map(|node| Playouts {
+            visits: node.visits,
+            rewards: std::array::from_fn(|player| node.rewards[player]),
+        })
+    }
+
+    /// Selects the move with the highest upper confidence bound, or the first
+    /// move that has not been tried yet.
+    fn select<S: Evaluate<N> + Play + Moves + ZobristHash, const N: usize>(
+        &self,
+        state: &mut S,
+        playouts: &Playouts<N>,
+    ) -> S::Move
+    where
+        S::Move: Copy,
+    {
+        let turn = state.turn();
+        let log_visits = f64::from(playouts.visits).ln();
+        let mut best = None;
+        let mut best_bound = f64::NEG_INFINITY;
+
+        let state_ptr: *mut S = state;
+        for m in state.moves() {
+            // Safety: as long as unplay properly restores any existing references that play
+            // destroys, this should be safe, right?
+            let next_state = unsafe { &mut *state_ptr };
+            let remember = next_state.play(&m);
+            let child: Option<Playouts<N>> = self.playouts(next_state);
+            next_state.unplay(remember);
+
+            let bound = match child {
+                Some(child) if child.visits > 0 => {
+                    let visits = f64::from(child.visits);
+                    child.mean(turn) + self.exploration * (log_visits / visits).sqrt()
+                }
+                _ => return m,
+            };
+
+            if bound > best_bound {
+                best = Some(m);
+                best_bound = bound;
+            }
+        }
+
+        best.expect("state has moves")
+    }
+
+    /// Plays random moves until the game ends or the playout depth is reached,
+    /// returning the rewards of the resulting state.
+    fn playout<S: Evaluate<N> + Play + Moves, const N: usize>(
+        &mut self,
+        state: &mut S,
+    ) -> [f64; N] {
+        let mut played = Vec::new();
+
+        for _ in 0..self.playout_depth {
+            match state.playout_move(self.random()) {
+                Some(m) => {
+                    played.push(state.play(&m));
+                    self.counter.visit();
+                }
+                None => break,
+            }
+        }
+
+        let rewards = rewards(&state.evaluate());
+
+        for remember in played.into_iter().rev() {
+            state.unplay(remember);
+        }
+
+        rewards
+    }
+}
+
+impl Default for Mcts {
+    fn default() -> Self {
+        Mcts::new(1000)
+    }
+}
+
+/// Statistics of a state in the search tree, with a reward for each player.
+#[derive(Clone, Debug)]
+struct Node {
+    visits: u32,
+    rewards: Vec<f64>,
+}
+
+/// Divides a reward of 1 among the players with the highest score.
+fn rewards<V: Score, const N: usize>(scores: &[V; N]) -> [f64; N] {
+    let max = scores.iter().copied().max();
+    let winners = scores.iter().filter(|&&score| Some(score) == max).count();
+
+    scores.map(|score| {
+        if Some(score) == max {
+            1.0 / winners as f64
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Statistics of the random playouts that passed through a state.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Playouts<const N: usize> {
+    /// The number of playouts that passed through this state.
+    pub visits: u32,
+    /// The sum of the rewards of each player over all playouts. A reward of 1
+    /// is split evenly between the players that won a playout.
+    pub rewards: [f64; N],
+}
+
+impl<const N: usize> Playouts<N> {
+    /// The average reward of the given player, ranging from 0 (never won) to 1
+    /// (always won on their own).
+    pub fn mean(&self, player: usize) -> f64 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.rewards[player] / f64::from(self.visits)
+        }
+    }
+}
+
+impl<const N: usize> HasMin for Playouts<N> {
+    const MIN: Self = Playouts {
+        visits: 0,
+        rewards: [0.0; N],
+    };
+}
+
+impl<S: Evaluate<N> + Play + Moves + ZobristHash + CacheKey, const N: usize, const CAP: usize>
+    Strategy<S, N, CAP> for Mcts
+where
+    S::Move: Copy,
+{
+    type Value = Playouts<N>;
+    type Score = S::Score;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        _cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.counter.start(interrupt);
+
+        // Every iteration improves the estimates, so an interrupted search is still useful
+        for _ in 0..u32::from(depth).saturating_mul(self.iterations) {
+            if self.counter.interrupted() {
+                break;
+            }
+
+            self.iterate(state);
+        }
+
+        // The most visited move is the most reliable estimate
+        let mut best = None;
+        let mut best_visits = 0;
+
+        let state_ptr: *mut S = state;
+        for m in state.moves() {
+            // Safety: as long as unplay properly restores any existing references that play
+            // destroys, this should be safe, right?
+            let next_state = unsafe { &mut *state_ptr };
+            let remember = next_state.play(&m);
+            let visits = self
+                .tree
+                .get(&next_state.zobrist_hash())
+                .map_or(0, |node| node.visits);
+            next_state.unplay(remember);
+
+            if visits > best_visits {
+                best = Some(m);
+                best_visits = visits;
+            }
+        }
+
+        SearchResult {
+            depth,
+            value: self.playouts(state).unwrap_or(Playouts::MIN),
+            bound: Bound::Exact,
+            best,
+        }
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+}
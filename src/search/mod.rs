@@ -1,46 +1,287 @@
-use std::ops::Neg;
+use std::{cmp::Reverse, ops::Neg};
 
-pub use negamax::Negamax;
+pub use best_reply::BestReply;
+pub use expectimax::Expectimax;
+pub use interrupt::{Interrupt, StopHandle};
+pub use max_n::MaxN;
+pub use mcts::{Mcts, Playouts};
+pub use mtdf::{Bounds, MtdF};
+pub use negamax::{Negamax, NegamaxParams, NoNullMoves, NullMoves, WithNullMoves};
+pub use paranoid::Paranoid;
 
 use crate::{
     cache::{CacheKey, TranspositionTable},
     moves::Moves,
-    Value,
+    Score, Value,
 };
 
+mod best_reply;
+mod expectimax;
+mod heuristics;
+mod interrupt;
 mod max_n;
+mod mcts;
+mod mtdf;
 mod negamax;
+mod paranoid;
 
 pub trait Strategy<S: Moves + CacheKey, const N: usize, const CAP: usize> {
     type Value: HasMin;
+    /// The type of the scores of the game.
+    type Score: Score;
 
+    /// Searches a state up to the given depth. If the search is interrupted
+    /// before it completes, the result only has a best move if it is at least
+    /// as good as the best move found by the previous search of the state.
     fn search(
+        &mut self,
         state: &mut S,
         depth: u8,
+        interrupt: &Interrupt,
         cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
     ) -> SearchResult<Self::Value, S::Move>;
+
+    /// Searches like [`search`], but ignores the given moves in the state
+    /// itself, so that the best move is one of the other moves. Strategies that
+    /// cannot ignore moves return a result without a best move if asked to.
+    ///
+    /// Without some of its moves, the state may be worth less than the
+    /// transposition table says, so strategies neither look up nor store the
+    /// state itself while ignoring moves. The states after its other moves
+    /// are cached as usual.
+    ///
+    /// [`search`]: Self::search
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        if excluded.is_empty() {
+            self.search(state, depth, interrupt, cache)
+        } else {
+            SearchResult::MIN
+        }
+    }
+
+    /// Scores every move of a state for the player to move, with the value a
+    /// search of the given depth finds for it, in the order of
+    /// [`Moves::moves`]. Only moves searched before an interruption are
+    /// scored. Strategies that cannot score moves in a single search return
+    /// `None`, in which case moves can still be ranked with
+    /// [`search_excluding`].
+    ///
+    /// [`search_excluding`]: Self::search_excluding
+    fn score_moves(
+        &mut self,
+        _state: &mut S,
+        _depth: u8,
+        _interrupt: &Interrupt,
+        _cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> Option<Vec<(S::Move, Self::Score)>> {
+        None
+    }
+
+    /// Prepares for a new search of a game, which may take many calls to
+    /// [`search`], such as one for every depth when searching deeper and
+    /// deeper. Strategies that learn from earlier searches can use this to
+    /// let older lessons count for less.
+    ///
+    /// [`search`]: Self::search
+    fn new_search(&mut self) {}
+
+    /// The total number of states this strategy has visited over all
+    /// searches, to compare the efficiency of different strategies. Strategies
+    /// that do not keep track return zero.
+    fn nodes(&self) -> u64 {
+        0
+    }
+
+    /// Indicates whether the last search was interrupted before it completed.
+    /// Strategies that cannot be interrupted return `false`.
+    fn interrupted(&self) -> bool {
+        false
+    }
+
+    /// Indicates whether the value found by a search of the state proves that
+    /// the player to move can force a win, as reported by
+    /// [`Evaluate::winner`], or the highest score the game allows, as given by
+    /// [`Evaluate::max_score`]. Strategies that cannot tell return `false`.
+    ///
+    /// [`Evaluate::winner`]: crate::Evaluate::winner
+    /// [`Evaluate::max_score`]: crate::Evaluate::max_score
+    fn is_forced_win(&self, _state: &S, _value: &Self::Value) -> bool {
+        false
+    }
+
+    /// The number of moves in which the player to move can force a win, as
+    /// proven by the value found by a search of the state, or the negative
+    /// number of moves in which the opponent can. Strategies that cannot tell
+    /// return `None`.
+    fn win_in(&self, _state: &S, _value: &Self::Value) -> Option<i16> {
+        None
+    }
+
+    /// The value found by a search of the state as a single score for the
+    /// player to move, if the strategy can express it that way. Strategies
+    /// that cannot return `None`.
+    fn score(&self, _state: &S, _value: &Self::Value) -> Option<Self::Score> {
+        None
+    }
+}
+
+/// Returns the moves of a state in the order they should be searched: the
+/// given move first if there is one, followed by the other moves from highest
+/// to lowest [`Moves::priority`]. The first move is usually the best move an
+/// earlier search found in the same state, which is likely to be among the
+/// best moves again.
+pub(crate) fn ordered_moves<S: Moves>(state: &S, first: Option<S::Move>) -> Vec<S::Move>
+where
+    S::Move: Copy + PartialEq,
+{
+    ordered_moves_by(state, first, |_| ())
+}
+
+/// Like [`ordered_moves`], but moves of equal priority are ordered from highest
+/// to lowest key.
+pub(crate) fn ordered_moves_by<S: Moves, K: Ord>(
+    state: &S,
+    first: Option<S::Move>,
+    mut key: impl FnMut(&S::Move) -> K,
+) -> Vec<S::Move>
+where
+    S::Move: Copy + PartialEq,
+{
+    let mut moves: Vec<_> = first
+        .into_iter()
+        .chain(state.moves().filter(|&m| Some(m) != first))
+        .collect();
+
+    // The sort is stable, so that equal moves keep the order they were generated in
+    let skip = usize::from(first.is_some());
+    moves[skip..].sort_by_cached_key(|m| Reverse((state.priority(m), key(m))));
+
+    moves
+}
+
+/// How many moves past the maximum depth the quiescence search looks by
+/// default.
+pub(crate) const QUIESCENCE_DEPTH: u8 = 8;
+
+/// Returns the [`Moves::noisy_moves`] of a state that is not quiet, from
+/// highest to lowest [`Moves::priority`], for the quiescence search past the
+/// maximum depth.
+pub(crate) fn noisy_moves<S: Moves>(state: &S) -> Vec<S::Move> {
+    let mut moves: Vec<_> = state.noisy_moves().collect();
+    // The sort is stable, so that equal moves keep the order they were generated in
+    moves.sort_by_cached_key(|m| Reverse(state.priority(m)));
+
+    moves
+}
+
+/// Converts a value found the given number of moves from the root into one
+/// that counts the moves to a forced win or loss from the state itself, so that
+/// it can be stored in the transposition table.
+pub(crate) fn to_cache<V: Score>(value: V, ply: usize) -> V {
+    match value.win_in() {
+        Some(moves) if value > -value => V::forced_win((moves as usize).saturating_sub(ply)),
+        Some(moves) => -V::forced_win((-moves as usize).saturating_sub(ply)),
+        None => value,
+    }
+}
+
+/// Converts a value stored in the transposition table back into one that
+/// counts the moves to a forced win or loss from the root, given the number of
+/// moves from the root to the state.
+pub(crate) fn from_cache<V: Score>(value: V, ply: usize) -> V {
+    match value.win_in() {
+        Some(moves) if value > -value => V::forced_win(moves as usize + ply),
+        Some(moves) => -V::forced_win(-moves as usize + ply),
+        None => value,
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
 pub struct SearchResult<V, M> {
     pub depth: u8,
     pub value: V,
+    /// How [`value`] relates to the actual value of the state.
+    ///
+    /// [`value`]: Self::value
+    pub bound: Bound,
     pub best: Option<M>,
 }
 
+/// How the value of a [`SearchResult`] relates to the actual value of the
+/// state. Alpha-beta searches stop looking at moves once it is clear that the
+/// value is outside of the window they are interested in, in which case the
+/// result is only a bound on the actual value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Bound {
+    /// The value is exact.
+    Exact,
+    /// The actual value is at least this value.
+    Lower,
+    /// The actual value is at most this value.
+    Upper,
+}
+
+impl Bound {
+    /// Determines what kind of bound the value found by a fail-soft alpha-beta
+    /// search is, given the window it searched with.
+    pub(crate) fn of<V: Ord>(value: V, alpha: V, beta: V) -> Self {
+        if value <= alpha {
+            Bound::Upper
+        } else if value >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        }
+    }
+}
+
+impl Neg for Bound {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Bound::Exact => Bound::Exact,
+            Bound::Lower => Bound::Upper,
+            Bound::Upper => Bound::Lower,
+        }
+    }
+}
+
 impl<V: HasMin, M> SearchResult<V, M> {
     const MIN: SearchResult<V, M> = SearchResult {
         depth: 0,
         value: V::MIN,
+        bound: Bound::Exact,
         best: None,
     };
 }
 
+impl<V, M> SearchResult<V, M> {
+    /// Settles the result of a search that was interrupted partway through
+    /// the moves of the state, given the best move of an earlier search that
+    /// was searched first, if any. A move cut short by the interrupt may be
+    /// undervalued, so only the moves searched in full count, and the best of
+    /// them is only worth keeping if that earlier best move is among them.
+    pub(crate) fn interrupted(&mut self, first: Option<M>) {
+        if first.is_none() {
+            self.best = None;
+        }
+    }
+}
+
 impl<V: Neg<Output = V>, M> Neg for SearchResult<V, M> {
     type Output = Self;
 
     fn neg(mut self) -> Self::Output {
         self.value = -self.value;
+        self.bound = -self.bound;
         self
     }
 }
@@ -49,11 +290,6 @@ pub trait HasMin {
     const MIN: Self;
 }
 
-impl HasMin for Value {
-    // Plus one to prevent overflow when negating
-    const MIN: Self = Value::MIN + 1;
-}
-
 impl<const N: usize> HasMin for [Value; N] {
     // Plus one to prevent overflow when negating
     const MIN: Self = [Value::MIN + 1; N];
@@ -0,0 +1,377 @@
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{
+        from_cache,
+        interrupt::Counter,
+        negamax::{quiesce, Sides, ZeroSum},
+        ordered_moves, to_cache, Bound, HasMin, Interrupt, SearchResult, Strategy,
+        QUIESCENCE_DEPTH,
+    },
+    EvaluateZeroSum, Moves, Play, Score, Value,
+};
+
+/// Search strategy for two-player zero-sum games, also known as MTD(f). The
+/// value of a state is found by repeatedly testing whether it is above or below
+/// a guess, using alpha-beta searches with a null window, and adjusting the
+/// guess accordingly. The transposition table keeps [`Bounds`] on the value of
+/// every state, so that these repeated searches rarely visit the same state
+/// twice.
+///
+/// The closer the first guess is to the actual value, the fewer searches are
+/// needed. Each search starts from the value found by the previous one, which
+/// is a good guess when [`Rival::get_best_within`] searches the same state at
+/// increasing depths.
+///
+/// Only games scored in [`Value`] are supported, as the guess moves in steps of
+/// whole numbers, and [`Bounds`] store values of that type.
+///
+/// [`Rival::get_best_within`]: crate::Rival::get_best_within
+#[derive(Clone, Debug)]
+pub struct MtdF {
+    guess: Value,
+    quiescence_depth: u8,
+    ply: usize,
+    counter: Counter,
+}
+
+impl MtdF {
+    pub fn new() -> Self {
+        MtdF {
+            guess: 0,
+            quiescence_depth: QUIESCENCE_DEPTH,
+            ply: 0,
+            counter: Counter::default(),
+        }
+    }
+
+    /// Sets the first guess of the next search. Defaults to zero.
+    pub fn with_guess(mut self, guess: Value) -> Self {
+        self.guess = guess;
+        self
+    }
+
+    /// Sets how many moves past the maximum depth the quiescence search looks
+    /// at most, as explained for [`Negamax`]. Defaults to 8.
+    ///
+    /// [`Negamax`]: crate::Negamax
+    pub fn with_quiescence_depth(mut self, quiescence_depth: u8) -> Self {
+        self.quiescence_depth = quiescence_depth;
+        self
+    }
+
+    /// Alpha-beta search that stores and uses bounds on the values of states,
+    /// rather than exact values.
+    fn search_alpha_beta<
+        S: Play + Moves + ZobristHash + CacheKey,
+        P: Sides<S, Score = Value>,
+        const CAP: usize,
+    >(
+        &mut self,
+        sides: &P,
+        state: &mut S,
+        depth: u8,
+        (mut alpha, mut beta): (Value, Value),
+        excluded: &[S::Move],
+        cache: &mut TranspositionTable<S, SearchResult<Bounds, S::Move>, CAP>,
+    ) -> SearchResult<Value, S::Move>
+    where
+        S::Move: Copy + PartialEq,
+    {
+        if self.counter.visit() {
+            return SearchResult::MIN;
+        }
+
+        let cacheable = sides.cacheable(state) && excluded.is_empty();
+        let cached = if cacheable {
+            cache.get(state).map(|entry| SearchResult {
+                value: Bounds {
+                    lower: from_cache(entry.value.lower, self.ply),
+                    upper: from_cache(entry.value.upper, self.ply),
+                },
+                ..*entry
+            })
+        } else {
+            None
+        };
+        let entry = cached.filter(|entry| entry.depth >= depth);
+
+        if let Some(entry) = entry {
+            let bound = |value| SearchResult {
+                depth: entry.depth,
+                value,
+                bound: Bound::Exact,
+                best: entry.best,
+            };
+
+            if entry.value.lower >= beta || entry.value.lower == entry.value.upper {
+                return bound(entry.value.lower);
+            }
+            if entry.value.upper <= alpha {
+                return bound(entry.value.upper);
+            }
+
+            alpha = alpha.max(entry.value.lower);
+            beta = beta.min(entry.value.upper);
+        }
+
+        let (original_alpha, original_beta) = (alpha, beta);
+
+        let (best, leaf) = if state.moves().next().is_none() {
+            let best = SearchResult {
+                depth: u8::MAX,
+                value: sides.value(state, self.ply),
+                bound: Bound::Exact,
+                best: None,
+            };
+
+            (best, true)
+        } else if depth == 0 && sides.quiet(state) {
+            let best = SearchResult {
+                depth: 0,
+                value: sides.value(state, self.ply),
+                bound: Bound::Exact,
+                best: None,
+            };
+
+            (best, true)
+        } else if depth == 0 {
+            let window = (alpha, beta);
+            let best = SearchResult {
+                depth: 0,
+                value: quiesce(
+                    sides,
+                    state,
+                    &mut self.counter,
+                    self.ply,
+                    self.quiescence_depth,
+                    window,
+                ),
+                bound: Bound::Exact,
+                best: None,
+            };
+
+            (best, false)
+        } else {
+            let mut best = SearchResult::MIN;
+
+            let state_ptr: *mut S = state;
+            let moves = ordered_moves(state, cached.and_then(|entry| entry.best));
+            for m in moves.into_iter().filter(|m| !excluded.contains(m)) {
+                // Safety: as long as unplay properly restores any existing references that play
+                // destroys, this should be safe, right?
+                let next_state = unsafe { &mut *state_ptr };
+                let side = sides.side(state);
+                let remember = next_state.play(&m);
+                self.ply += 1;
+                let current = if side == sides.side(next_state) {
+                    self.search_alpha_beta(sides, next_state, depth - 1, (alpha, beta), &[], cache)
+                } else {
+                    -self.search_alpha_beta(
+                        sides,
+                        next_state,
+                        depth - 1,
+                        (-beta, -alpha),
+                        &[],
+                        cache,
+                    )
+                };
+                self.ply -= 1;
+                next_state.unplay(remember);
+
+                if self.counter.interrupted() {
+                    break;
+                }
+
+                // Even a move that loses in the worst possible way beats no move at all
+                if current.value > best.value || best.best.is_none() {
+                    alpha = alpha.max(current.value);
+                    best = SearchResult {
+                        depth: current.depth.saturating_add(1),
+                        value: current.value,
+                        bound: Bound::Exact,
+                        best: Some(m),
+                    };
+                }
+
+                if alpha >= beta {
+                    break;
+                }
+            }
+
+            (best, false)
+        };
+
+        if self.counter.interrupted() {
+            return best;
+        }
+
+        if cacheable {
+            // Bounds found earlier remain valid, as long as they do not contradict the new ones
+            let mut bounds = entry.map_or(Bounds::UNKNOWN, |entry| entry.value);
+            let mut best_move = best.best;
+
+            if leaf || (original_alpha < best.value && best.value < original_beta) {
+                bounds = Bounds::exact(best.value);
+            } else if best.value <= original_alpha {
+                bounds.upper = best.value.max(bounds.lower);
+                // Every move failed low, so none of them is known to be better than the previous best,
+                // if any
+                best_move = cached.and_then(|entry| entry.best).or(best_move);
+            } else {
+                bounds.lower = best.value.min(bounds.upper);
+            }
+
+            let depth = entry.map_or(best.depth, |entry| entry.depth.min(best.depth));
+
+            cache.insert(
+                state,
+                SearchResult {
+                    depth,
+                    value: Bounds {
+                        lower: to_cache(bounds.lower, self.ply),
+                        upper: to_cache(bounds.upper, self.ply),
+                    },
+                    bound: Bound::Exact,
+                    best: best_move,
+                },
+            );
+        }
+
+        best
+    }
+}
+
+impl Default for MtdF {
+    fn default() -> Self {
+        MtdF::new()
+    }
+}
+
+/// Bounds on the value of a state, as stored in the transposition table by
+/// [`MtdF`]. The actual value lies somewhere between `lower` and `upper`,
+/// inclusive.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Bounds {
+    pub lower: Value,
+    pub upper: Value,
+}
+
+impl Bounds {
+    const UNKNOWN: Bounds = Bounds {
+        lower: Value::MIN + 1,
+        upper: Value::MAX,
+    };
+
+    const fn exact(value: Value) -> Self {
+        Bounds {
+            lower: value,
+            upper: value,
+        }
+    }
+}
+
+impl HasMin for Bounds {
+    const MIN: Self = Bounds::exact(Value::MIN + 1);
+}
+
+impl<
+        S: EvaluateZeroSum<Score = Value> + Play + Moves + ZobristHash + CacheKey,
+        const CAP: usize,
+    > Strategy<S, 2, CAP> for MtdF
+where
+    S::Move: Copy + PartialEq,
+{
+    type Value = Bounds;
+    type Score = Value;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.counter.start(interrupt);
+
+        let mut bounds = Bounds::UNKNOWN;
+        let mut result = SearchResult {
+            depth,
+            value: self.guess,
+            bound: Bound::Exact,
+            best: None,
+        };
+        let mut best = None;
+
+        while bounds.lower < bounds.upper {
+            let beta = if result.value == bounds.lower {
+                result.value + 1
+            } else {
+                result.value
+            };
+
+            let window = (beta - 1, beta);
+            result = self.search_alpha_beta(&ZeroSum, state, depth, window, excluded, cache);
+
+            if self.counter.interrupted() {
+                // The best move is only certain once the bounds meet
+                return SearchResult {
+                    depth: 0,
+                    value: bounds,
+                    bound: Bound::Exact,
+                    best: None,
+                };
+            }
+
+            if result.value < beta {
+                bounds.upper = result.value;
+            } else {
+                bounds.lower = result.value;
+            }
+
+            // Only a search that failed high is certain to have found the best move
+            if result.value >= beta || best.is_none() {
+                best = result.best;
+            }
+        }
+
+        self.guess = result.value;
+
+        SearchResult {
+            depth: result.depth,
+            value: bounds,
+            bound: Bound::Exact,
+            best,
+        }
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, _state: &S, value: &Self::Value) -> bool {
+        value.lower.win_in().is_some_and(|moves| moves > 0)
+    }
+
+    fn win_in(&self, _state: &S, value: &Self::Value) -> Option<i16> {
+        value.lower.win_in()
+    }
+
+    fn score(&self, _state: &S, value: &Self::Value) -> Option<Value> {
+        Some(value.lower)
+    }
+}
@@ -1,111 +1,773 @@
+use std::{hash::Hash, marker::PhantomData};
+
 use crate::{
     cache::{CacheKey, TranspositionTable, ZobristHash},
-    search::SearchResult,
-    Evaluate, EvaluateZeroSum, Moves, Play, Strategy, Value,
+    search::{
+        from_cache, heuristics::Heuristics, interrupt::Counter, noisy_moves, ordered_moves,
+        ordered_moves_by, to_cache, Bound, HasMin, Interrupt, SearchResult, QUIESCENCE_DEPTH,
+    },
+    EvaluateZeroSum, Moves, NullMove, Play, Score, Strategy,
 };
 
-#[derive(Copy, Clone, Debug)]
-pub struct Negamax;
+/// Search strategy for two-player zero-sum games, using alpha-beta pruning to
+/// skip moves that cannot affect the result.
+///
+/// Optionally, only the first move of every state is searched with the full
+/// window, while the other moves are merely tested to be worse using a null
+/// window, also known as Principal Variation Search. Only moves that fail this
+/// test are searched again with the full window. If the moves tend to be
+/// ordered from best to worst, this visits fewer states.
+///
+/// The best move found by an earlier search of a state is always searched
+/// first, even if that search did not look ahead far enough to be reused.
+/// The other moves are searched in order of their [`Moves::priority`]. Moves
+/// of equal priority that recently caused a cutoff at the same distance from
+/// the root (killer moves) are searched first, followed by the moves that
+/// caused the most cutoffs overall (history heuristic). These heuristics only
+/// learn from moves with a priority of at most zero that were not searched
+/// first, and carry over from one search to the next.
+///
+/// Past the maximum depth, states that are not [`Evaluate::quiet`] are searched
+/// further, but only their [`Moves::noisy_moves`], and the player to move may
+/// also settle for the evaluation of the state instead. This quiescence search
+/// looks at most 8 moves further by default.
+///
+/// Once enabled with [`with_null_moves`], in games that can pass the turn
+/// through [`NullMove`], the player to move first tests whether they would be
+/// doing well enough even after passing, with a search that is a few moves
+/// shallower. If so, their moves are skipped, as at least one of them is
+/// likely to do better still. This null-move pruning reduces the depth by 2 by
+/// default.
+///
+/// Moves can also be searched deeper or shallower than the rest, as set by
+/// [`NegamaxParams`]. Moves that [`Moves::extend`] asks for are searched
+/// further, while late moves of low priority may be searched less far, and
+/// only searched again to the full depth if they turn out better than expected.
+///
+/// [`with_null_moves`]: Self::with_null_moves
+/// [`Evaluate::quiet`]: crate::Evaluate::quiet
+#[derive(Clone, Debug)]
+pub struct Negamax<M = NoNullMoves> {
+    principal_variation: bool,
+    params: NegamaxParams,
+    after_null_move: bool,
+    extended: u8,
+    heuristics: Option<Heuristics>,
+    ply: usize,
+    pub(crate) counter: Counter,
+    null_moves: PhantomData<M>,
+}
+
+/// Decides whether [`Negamax`] passes the turn to skip moves, as set by
+/// [`Negamax::with_null_moves`].
+pub trait NullMoves<S: Play> {
+    /// Passes the turn, if allowed in this state, returning data to undo the
+    /// pass with [`unplay_null`].
+    ///
+    /// [`unplay_null`]: Self::unplay_null
+    fn play_null(state: &mut S) -> Option<S::Remember>;
+
+    /// Undoes passing the turn.
+    fn unplay_null(state: &mut S, remember: S::Remember);
+}
+
+/// Never passes the turn, which [`Negamax`] does by default.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NoNullMoves;
+
+impl<S: Play> NullMoves<S> for NoNullMoves {
+    fn play_null(_state: &mut S) -> Option<S::Remember> {
+        None
+    }
+
+    fn unplay_null(state: &mut S, remember: S::Remember) {
+        state.unplay(remember);
+    }
+}
+
+/// Passes the turn whenever [`NullMove::null_move_safe`] allows it.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct WithNullMoves;
+
+impl<S: NullMove> NullMoves<S> for WithNullMoves {
+    fn play_null(state: &mut S) -> Option<S::Remember> {
+        state.null_move_safe().then(|| state.play_null())
+    }
+
+    fn unplay_null(state: &mut S, remember: S::Remember) {
+        state.unplay_null(remember);
+    }
+}
+
+/// Parameters of [`Negamax`] that decide how far it searches some states and
+/// moves compared to the maximum depth.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NegamaxParams {
+    /// How many moves past the maximum depth the quiescence search looks at
+    /// most. Defaults to 8.
+    pub quiescence_depth: u8,
+    /// By how many moves the search after passing the turn is shallower than
+    /// the search it may skip. Zero disables null-move pruning. Defaults to 2.
+    pub null_move_reduction: u8,
+    /// By how many moves late moves are searched shallower than the others.
+    /// Only moves with a [`Moves::priority`] of at most zero count as late,
+    /// once the first few moves of a state have been searched. Zero disables
+    /// late-move reductions. Defaults to 0.
+    pub late_move_reduction: u8,
+    /// How many moves of every state are searched to the full depth before
+    /// later moves may be reduced. Defaults to 3.
+    pub full_depth_moves: usize,
+    /// How many moves ahead a state must still be searched for its late moves
+    /// to be reduced. Defaults to 3.
+    pub reduction_depth: u8,
+    /// How many moves in total [`Moves::extend`] may add to a line, so that
+    /// lines of moves that are all extended still end. Defaults to 2.
+    pub max_extension: u8,
+}
+
+impl Default for NegamaxParams {
+    fn default() -> Self {
+        NegamaxParams {
+            quiescence_depth: QUIESCENCE_DEPTH,
+            null_move_reduction: 2,
+            late_move_reduction: 0,
+            full_depth_moves: 3,
+            reduction_depth: 3,
+            max_extension: 2,
+        }
+    }
+}
+
+/// Describes how the players of a game are divided into the two sides of a
+/// negamax search.
+pub(crate) trait Sides<S> {
+    type Score: Score;
+
+    /// Indicates which side is to move. Consecutive states with the same side
+    /// to move are searched without negating the value.
+    fn side(&self, state: &S) -> bool;
+
+    /// Evaluates the current state from the perspective of the side to move.
+    fn evaluate(&self, state: &S) -> Self::Score;
+
+    /// Indicates whether the side to move has won (`true`) or lost (`false`),
+    /// if either side has won.
+    fn won(&self, state: &S) -> Option<bool>;
+
+    /// Indicates whether the search should continue past the maximum depth.
+    fn quiet(&self, state: &S) -> bool;
+
+    /// Evaluates the current state like [`evaluate`], but scores a state in
+    /// which either side has won as a forced win or loss the given number of
+    /// moves from the root.
+    ///
+    /// [`evaluate`]: Self::evaluate
+    fn value(&self, state: &S, ply: usize) -> Self::Score {
+        match self.won(state) {
+            Some(true) => Self::Score::forced_win(ply),
+            Some(false) => -Self::Score::forced_win(ply),
+            None => self.evaluate(state),
+        }
+    }
+
+    /// Indicates whether the value of this state is independent of the search
+    /// it is part of, so that it can be stored in and retrieved from the
+    /// transposition table.
+    fn cacheable(&self, _state: &S) -> bool {
+        true
+    }
+}
+
+/// The two players of a zero-sum game.
+pub(crate) struct ZeroSum;
+
+impl<S: EvaluateZeroSum> Sides<S> for ZeroSum {
+    type Score = S::Score;
+
+    fn side(&self, state: &S) -> bool {
+        state.min_turn()
+    }
+
+    fn evaluate(&self, state: &S) -> S::Score {
+        if state.min_turn() {
+            -state.evaluate()
+        } else {
+            state.evaluate()
+        }
+    }
+
+    fn won(&self, state: &S) -> Option<bool> {
+        state.is_win().map(|max| max != state.min_turn())
+    }
+
+    fn quiet(&self, state: &S) -> bool {
+        state.quiet()
+    }
+}
 
 impl Negamax {
-    fn search_alpha_beta<
-        S: EvaluateZeroSum + Play + Moves + ZobristHash + CacheKey,
+    pub fn new() -> Self {
+        Negamax {
+            principal_variation: false,
+            params: NegamaxParams::default(),
+            after_null_move: false,
+            extended: 0,
+            heuristics: Some(Heuristics::default()),
+            ply: 0,
+            counter: Counter::default(),
+            null_moves: PhantomData,
+        }
+    }
+
+    /// Enables null-move pruning, which requires the game to implement
+    /// [`NullMove`].
+    pub fn with_null_moves(self) -> Negamax<WithNullMoves> {
+        Negamax {
+            principal_variation: self.principal_variation,
+            params: self.params,
+            after_null_move: self.after_null_move,
+            extended: self.extended,
+            heuristics: self.heuristics,
+            ply: self.ply,
+            counter: self.counter,
+            null_moves: PhantomData,
+        }
+    }
+}
+
+impl<M> Negamax<M> {
+    /// Sets whether to use Principal Variation Search. Defaults to `false`.
+    pub fn with_principal_variation(mut self, principal_variation: bool) -> Self {
+        self.principal_variation = principal_variation;
+        self
+    }
+
+    /// Sets how many moves past the maximum depth the quiescence search looks
+    /// at most. Defaults to 8.
+    pub fn with_quiescence_depth(mut self, quiescence_depth: u8) -> Self {
+        self.params.quiescence_depth = quiescence_depth;
+        self
+    }
+
+    /// Sets by how many moves the search after passing the turn is shallower
+    /// than the search it may skip. Zero disables null-move pruning. Defaults
+    /// to 2.
+    pub fn with_null_move_reduction(mut self, null_move_reduction: u8) -> Self {
+        self.params.null_move_reduction = null_move_reduction;
+        self
+    }
+
+    /// Sets all parameters that decide how far states and moves are searched
+    /// at once, such as late-move reductions and extensions. Defaults to
+    /// [`NegamaxParams::default`].
+    pub fn with_params(mut self, params: NegamaxParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Sets whether to search killer moves and moves with a good history
+    /// first. Defaults to `true`.
+    pub fn with_heuristics(mut self, heuristics: bool) -> Self {
+        self.heuristics = heuristics.then(Heuristics::default);
+        self
+    }
+
+    /// Prepares for a new search, which stops when the interrupt is due.
+    pub(crate) fn start_search(&mut self, interrupt: &Interrupt) {
+        self.counter.start(interrupt);
+    }
+
+    /// Lets the cutoffs of earlier searches weigh less than those to come.
+    pub(crate) fn age_heuristics(&mut self) {
+        if let Some(heuristics) = &mut self.heuristics {
+            heuristics.age();
+        }
+    }
+
+    pub(crate) fn search_alpha_beta<
+        S: Play + Moves + ZobristHash + CacheKey,
+        P: Sides<S>,
         const CAP: usize,
     >(
+        &mut self,
+        sides: &P,
         state: &mut S,
         depth: u8,
-        mut alpha: Value,
-        beta: Value,
-        cache: &mut TranspositionTable<
-            S,
-            SearchResult<<Negamax as Strategy<S, 2, CAP>>::Value, S::Move>,
-            CAP,
-        >,
-    ) -> SearchResult<<Negamax as Strategy<S, 2, CAP>>::Value, S::Move>
+        (mut alpha, mut beta): (P::Score, P::Score),
+        excluded: &[S::Move],
+        cache: &mut TranspositionTable<S, SearchResult<P::Score, S::Move>, CAP>,
+    ) -> SearchResult<P::Score, S::Move>
     where
-        S::Move: Copy,
+        S::Move: Copy + PartialEq + Hash,
+        M: NullMoves<S>,
     {
-        if let Some(result) = cache.get(state) {
-            if result.depth >= depth {
-                return *result;
+        // Passing twice in a row would only make the search shallower
+        let after_null_move = std::mem::take(&mut self.after_null_move);
+
+        if self.counter.visit() {
+            return SearchResult::MIN;
+        }
+
+        let cacheable = sides.cacheable(state) && excluded.is_empty();
+        let cached = if cacheable {
+            cache.get(state).map(|result| SearchResult {
+                value: from_cache(result.value, self.ply),
+                ..*result
+            })
+        } else {
+            None
+        };
+
+        if let Some(result) = cached.filter(|result| result.depth >= depth) {
+            // A bound inside the window does not settle the value, but it does narrow the window
+            match result.bound {
+                Bound::Exact => return result,
+                Bound::Lower if result.value >= beta => return result,
+                Bound::Upper if result.value <= alpha => return result,
+                Bound::Lower => alpha = alpha.max(result.value),
+                Bound::Upper => beta = beta.min(result.value),
             }
         }
 
+        let (original_alpha, original_beta) = (alpha, beta);
+
         let best = if state.moves().next().is_none() {
             SearchResult {
                 depth: u8::MAX,
-                value: if state.min_turn() {
-                    -state.evaluate()
-                } else {
-                    state.evaluate()
-                },
+                value: sides.value(state, self.ply),
+                bound: Bound::Exact,
                 best: None,
             }
-        } else if depth == 0 && state.quiet() {
+        } else if depth == 0 && sides.quiet(state) {
             SearchResult {
                 depth: 0,
-                value: if state.min_turn() {
-                    -state.evaluate()
-                } else {
-                    state.evaluate()
-                },
+                value: sides.value(state, self.ply),
+                bound: Bound::Exact,
+                best: None,
+            }
+        } else if depth == 0 {
+            let window = (alpha, beta);
+            let value = quiesce(
+                sides,
+                state,
+                &mut self.counter,
+                self.ply,
+                self.params.quiescence_depth,
+                window,
+            );
+
+            if self.counter.interrupted() {
+                return SearchResult::MIN;
+            }
+
+            SearchResult {
+                depth: 0,
+                value,
+                bound: Bound::of(value, original_alpha, original_beta),
                 best: None,
             }
+        } else if let Some(value) =
+            self.search_null_move(sides, state, depth, beta, after_null_move, cache)
+        {
+            SearchResult {
+                depth,
+                value,
+                bound: Bound::Lower,
+                best: cached.and_then(|result| result.best),
+            }
         } else {
             let mut best = SearchResult::MIN;
 
             let state_ptr: *mut S = state;
-            for m in state.moves() {
+            let first = cached.and_then(|result| result.best);
+            let moves = match &self.heuristics {
+                Some(heuristics) => {
+                    ordered_moves_by(state, first, |m| heuristics.score(self.ply, m))
+                }
+                None => ordered_moves(state, first),
+            };
+            let moves = moves.into_iter().filter(|m| !excluded.contains(m));
+            for (i, m) in moves.enumerate() {
                 // Safety: as long as unplay properly restores any existing references that play
                 // destroys, this should be safe, right?
                 let next_state = unsafe { &mut *state_ptr };
-                let turn = state.turn();
+                let side = sides.side(state);
+                let extension = self.extension(state, &m);
+                let reduction = if extension == 0 {
+                    self.reduction(state, &m, i, depth)
+                } else {
+                    0
+                };
                 let remember = next_state.play(&m);
-                let current = if turn == next_state.turn() {
-                    Self::search_alpha_beta(next_state, depth - 1, alpha, beta, cache)
+                let same_side = side == sides.side(next_state);
+                let scout = self.principal_variation && i > 0;
+                let child_depth = (depth - 1).saturating_add(extension);
+                self.extended += extension;
+                // After the first move, only test whether the other moves are any better
+                let window = if scout || reduction > 0 {
+                    (alpha, alpha.successor())
                 } else {
-                    -Self::search_alpha_beta(next_state, depth - 1, -beta, -alpha, cache)
+                    (alpha, beta)
                 };
+                let mut current = self.search_child(
+                    sides,
+                    next_state,
+                    same_side,
+                    child_depth - reduction,
+                    window,
+                    cache,
+                );
+                if reduction > 0 && alpha < current.value {
+                    // This late move may be better after all, so search it to the full depth
+                    let window = if scout {
+                        (alpha, alpha.successor())
+                    } else {
+                        (alpha, beta)
+                    };
+                    current =
+                        self.search_child(sides, next_state, same_side, child_depth, window, cache);
+                }
+                if scout && alpha < current.value && current.value < beta {
+                    // This move is better, so its actual value is needed
+                    current = self.search_child(
+                        sides,
+                        next_state,
+                        same_side,
+                        child_depth,
+                        (alpha, beta),
+                        cache,
+                    );
+                }
+                self.extended -= extension;
                 next_state.unplay(remember);
 
-                if current.value > best.value {
+                if self.counter.interrupted() {
+                    break;
+                }
+
+                // Even a move that loses in the worst possible way beats no move at all
+                if current.value > best.value || best.best.is_none() {
                     alpha = alpha.max(current.value);
                     best = SearchResult {
                         depth: current.depth.saturating_add(1),
                         value: current.value,
+                        bound: Bound::Exact,
                         best: Some(m),
                     };
                 }
 
                 if alpha >= beta {
+                    // Noisy moves and the move from the cache are searched early anyway
+                    if Some(m) != first && state.priority(&m) <= 0 {
+                        if let Some(heuristics) = &mut self.heuristics {
+                            heuristics.cutoff(self.ply, &m, depth);
+                        }
+                    }
                     break;
                 }
             }
 
+            if self.counter.interrupted() {
+                best.interrupted(first);
+
+                return best;
+            }
+
+            best.bound = Bound::of(best.value, original_alpha, original_beta);
+
+            // When every move failed low, the one with the highest bound is not necessarily the
+            // best, so keep the move found by an earlier search instead, if any
+            if best.bound == Bound::Upper {
+                best.best = cached.and_then(|result| result.best).or(best.best);
+            }
+
             best
         };
 
-        cache.insert(state, best);
+        if cacheable {
+            let value = to_cache(best.value, self.ply);
+            cache.insert(state, SearchResult { value, ..best });
+        }
 
         best
     }
+
+    /// Tests whether the side to move would still get at least beta after
+    /// passing the turn, with a shallower search. Returns the value found if
+    /// so, which is a lower bound on the value of the state, as long as
+    /// passing is not better than every move.
+    fn search_null_move<S: Play + Moves + ZobristHash + CacheKey, P: Sides<S>, const CAP: usize>(
+        &mut self,
+        sides: &P,
+        state: &mut S,
+        depth: u8,
+        beta: P::Score,
+        after_null_move: bool,
+        cache: &mut TranspositionTable<S, SearchResult<P::Score, S::Move>, CAP>,
+    ) -> Option<P::Score>
+    where
+        S::Move: Copy + PartialEq + Hash,
+        M: NullMoves<S>,
+    {
+        let reduction = self.params.null_move_reduction;
+        if reduction == 0 || depth <= reduction || self.ply == 0 || after_null_move {
+            return None;
+        }
+
+        let side = sides.side(state);
+        let remember = M::play_null(state)?;
+        let same_side = side == sides.side(state);
+        // Only test whether passing is at least as good as beta
+        let window = (-(-beta).successor(), beta);
+        self.after_null_move = true;
+        let depth = depth - reduction - 1;
+        let result = self.search_child(sides, state, same_side, depth, window, cache);
+        M::unplay_null(state, remember);
+
+        if self.counter.interrupted() || result.value < beta {
+            None
+        } else if result.value.win_in().is_some() {
+            // A forced win after passing is not one the side to move can count on
+            Some(beta)
+        } else {
+            Some(result.value)
+        }
+    }
+
+    /// How many moves deeper to search after the given move, within what is
+    /// left of the maximum extension of the current line.
+    fn extension<S: Moves>(&self, state: &S, m: &S::Move) -> u8 {
+        let extension = state.extend(m);
+        if extension == 0 {
+            return 0;
+        }
+
+        extension.min(self.params.max_extension.saturating_sub(self.extended))
+    }
+
+    /// By how many moves to reduce the search of the move with the given index
+    /// among the moves of a state.
+    fn reduction<S: Moves>(&self, state: &S, m: &S::Move, i: usize, depth: u8) -> u8 {
+        let params = &self.params;
+        if params.late_move_reduction == 0
+            || i < params.full_depth_moves
+            || depth < params.reduction_depth
+            || state.priority(m) > 0
+        {
+            return 0;
+        }
+
+        // Always search at least the move itself
+        params.late_move_reduction.min(depth - 1)
+    }
+
+    /// Searches the state after a move, from the perspective of the side that
+    /// played it.
+    /// Searches every move of the state with the full window, so that each
+    /// gets an exact value rather than a bound, in the order of
+    /// [`Moves::moves`]. The states after the moves share the transposition
+    /// table as in any other search.
+    pub(crate) fn search_every_move<
+        S: Play + Moves + ZobristHash + CacheKey,
+        P: Sides<S>,
+        const CAP: usize,
+    >(
+        &mut self,
+        sides: &P,
+        state: &mut S,
+        depth: u8,
+        cache: &mut TranspositionTable<S, SearchResult<P::Score, S::Move>, CAP>,
+    ) -> Vec<(S::Move, P::Score)>
+    where
+        S::Move: Copy + PartialEq + Hash,
+        M: NullMoves<S>,
+    {
+        let mut scored = Vec::new();
+
+        if self.counter.visit() {
+            return scored;
+        }
+
+        let state_ptr: *mut S = state;
+        for m in state.moves() {
+            // Safety: as long as unplay properly restores any existing references that play
+            // destroys, this should be safe, right?
+            let next_state = unsafe { &mut *state_ptr };
+            let side = sides.side(state);
+            let extension = self.extension(state, &m);
+            let remember = next_state.play(&m);
+            let same_side = side == sides.side(next_state);
+            let child_depth = (depth - 1).saturating_add(extension);
+            self.extended += extension;
+            let window = (P::Score::MIN, P::Score::MAX);
+            let current =
+                self.search_child(sides, next_state, same_side, child_depth, window, cache);
+            self.extended -= extension;
+            next_state.unplay(remember);
+
+            if self.counter.interrupted() {
+                break;
+            }
+
+            scored.push((m, current.value));
+        }
+
+        scored
+    }
+
+    fn search_child<S: Play + Moves + ZobristHash + CacheKey, P: Sides<S>, const CAP: usize>(
+        &mut self,
+        sides: &P,
+        state: &mut S,
+        same_side: bool,
+        depth: u8,
+        (alpha, beta): (P::Score, P::Score),
+        cache: &mut TranspositionTable<S, SearchResult<P::Score, S::Move>, CAP>,
+    ) -> SearchResult<P::Score, S::Move>
+    where
+        S::Move: Copy + PartialEq + Hash,
+        M: NullMoves<S>,
+    {
+        self.ply += 1;
+        let result = if same_side {
+            self.search_alpha_beta(sides, state, depth, (alpha, beta), &[], cache)
+        } else {
+            -self.search_alpha_beta(sides, state, depth, (-beta, -alpha), &[], cache)
+        };
+        self.ply -= 1;
+
+        result
+    }
 }
 
-impl<S: EvaluateZeroSum + Play + Moves + ZobristHash + CacheKey, const CAP: usize>
-    Strategy<S, 2, CAP> for Negamax
+/// Searches the noisy moves of a state that is not quiet, the given number of
+/// moves from the root, until the states are quiet or the quiescence depth runs
+/// out. The side to move may also stand pat, settling for the evaluation of the
+/// state instead of making a noisy move. Returns a fail-soft value like an
+/// alpha-beta search.
+pub(crate) fn quiesce<S: Play + Moves, P: Sides<S>>(
+    sides: &P,
+    state: &mut S,
+    counter: &mut Counter,
+    ply: usize,
+    depth: u8,
+    (mut alpha, beta): (P::Score, P::Score),
+) -> P::Score
 where
     S::Move: Copy,
 {
-    type Value = Value;
+    let stand_pat = sides.value(state, ply);
+    if depth == 0 || sides.quiet(state) || stand_pat >= beta {
+        return stand_pat;
+    }
+
+    let mut best = stand_pat;
+    alpha = alpha.max(stand_pat);
+
+    for m in noisy_moves(state) {
+        if counter.visit() {
+            break;
+        }
+
+        let side = sides.side(state);
+        let remember = state.play(&m);
+        let current = if side == sides.side(state) {
+            quiesce(sides, state, counter, ply + 1, depth - 1, (alpha, beta))
+        } else {
+            -quiesce(sides, state, counter, ply + 1, depth - 1, (-beta, -alpha))
+        };
+        state.unplay(remember);
+
+        if counter.interrupted() {
+            break;
+        }
+
+        best = best.max(current);
+        alpha = alpha.max(current);
+
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    best
+}
+
+impl Default for Negamax {
+    fn default() -> Self {
+        Negamax::new()
+    }
+}
+
+impl<
+        S: EvaluateZeroSum + Play + Moves + ZobristHash + CacheKey,
+        M: NullMoves<S>,
+        const CAP: usize,
+    > Strategy<S, 2, CAP> for Negamax<M>
+where
+    S::Move: Copy + PartialEq + Hash,
+{
+    type Value = S::Score;
+    type Score = S::Score;
 
     fn search(
+        &mut self,
         state: &mut S,
         depth: u8,
+        interrupt: &Interrupt,
         cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
     ) -> SearchResult<Self::Value, S::Move> {
-        let alpha = Value::MIN + 1;
-        let beta = Value::MAX;
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        let alpha = S::Score::MIN;
+        let beta = S::Score::MAX;
+        self.start_search(interrupt);
+
+        self.search_alpha_beta(&ZeroSum, state, depth, (alpha, beta), excluded, cache)
+    }
+
+    fn score_moves(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> Option<Vec<(S::Move, Self::Score)>> {
+        self.start_search(interrupt);
+
+        Some(self.search_every_move(&ZeroSum, state, depth, cache))
+    }
+
+    fn new_search(&mut self) {
+        self.age_heuristics();
+    }
+
+    fn nodes(&self) -> u64 {
+        self.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, _state: &S, value: &Self::Value) -> bool {
+        value.win_in().is_some_and(|moves| moves > 0)
+    }
+
+    fn win_in(&self, _state: &S, value: &Self::Value) -> Option<i16> {
+        value.win_in()
+    }
 
-        Self::search_alpha_beta(state, depth, alpha, beta, cache)
+    fn score(&self, _state: &S, value: &Self::Value) -> Option<Self::Score> {
+        Some(*value)
     }
 }
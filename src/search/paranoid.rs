@@ -0,0 +1,130 @@
+use std::hash::Hash;
+
+use crate::{
+    cache::{CacheKey, TranspositionTable, ZobristHash},
+    search::{negamax::Sides, HasMin, Interrupt, Negamax, SearchResult, Strategy},
+    Evaluate, Moves, Play, Score,
+};
+
+/// Search strategy for games with any number of players. The player to move
+/// assumes all other players have formed a coalition against them, trying to
+/// minimize their score. This turns the game into a two-player game, allowing
+/// the same pruning as [`Negamax`].
+#[derive(Clone, Debug, Default)]
+pub struct Paranoid {
+    negamax: Negamax,
+}
+
+/// The player the search was started for, and the coalition of everyone else.
+struct Coalition<const N: usize> {
+    root: usize,
+}
+
+impl<S: Evaluate<N>, const N: usize> Sides<S> for Coalition<N> {
+    type Score = S::Score;
+
+    fn side(&self, state: &S) -> bool {
+        state.turn() == self.root
+    }
+
+    fn evaluate(&self, state: &S) -> S::Score {
+        let value = state.evaluate()[self.root];
+
+        if self.side(state) {
+            value
+        } else {
+            -value
+        }
+    }
+
+    fn won(&self, state: &S) -> Option<bool> {
+        state
+            .winner()
+            .map(|winner| (winner == self.root) == self.side(state))
+    }
+
+    fn quiet(&self, state: &S) -> bool {
+        state.quiet()
+    }
+
+    // The value of a state depends on who started the search, unless that player is to move
+    fn cacheable(&self, state: &S) -> bool {
+        self.side(state)
+    }
+}
+
+impl<S: Evaluate<N> + Play + Moves + ZobristHash + CacheKey, const N: usize, const CAP: usize>
+    Strategy<S, N, CAP> for Paranoid
+where
+    S::Move: Copy + PartialEq + Hash,
+{
+    type Value = S::Score;
+    type Score = S::Score;
+
+    fn search(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> SearchResult<Self::Value, S::Move> {
+        self.search_excluding(state, depth, interrupt, cache, &[])
+    }
+
+    fn search_excluding(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+        excluded: &[S::Move],
+    ) -> SearchResult<Self::Value, S::Move> {
+        let alpha = S::Score::MIN;
+        let beta = S::Score::MAX;
+        let coalition = Coalition::<N> { root: state.turn() };
+        self.negamax.start_search(interrupt);
+
+        self.negamax
+            .search_alpha_beta(&coalition, state, depth, (alpha, beta), excluded, cache)
+    }
+
+    fn score_moves(
+        &mut self,
+        state: &mut S,
+        depth: u8,
+        interrupt: &Interrupt,
+        cache: &mut TranspositionTable<S, SearchResult<Self::Value, S::Move>, CAP>,
+    ) -> Option<Vec<(S::Move, Self::Score)>> {
+        let coalition = Coalition::<N> { root: state.turn() };
+        self.negamax.start_search(interrupt);
+
+        Some(
+            self.negamax
+                .search_every_move(&coalition, state, depth, cache),
+        )
+    }
+
+    fn new_search(&mut self) {
+        self.negamax.age_heuristics();
+    }
+
+    fn nodes(&self) -> u64 {
+        self.negamax.counter.nodes()
+    }
+
+    fn interrupted(&self) -> bool {
+        self.negamax.counter.interrupted()
+    }
+
+    fn is_forced_win(&self, _state: &S, value: &Self::Value) -> bool {
+        value.win_in().is_some_and(|moves| moves > 0)
+    }
+
+    fn win_in(&self, _state: &S, value: &Self::Value) -> Option<i16> {
+        value.win_in()
+    }
+
+    fn score(&self, _state: &S, value: &Self::Value) -> Option<Self::Score> {
+        Some(*value)
+    }
+}